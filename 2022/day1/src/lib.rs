@@ -0,0 +1,340 @@
+//! 2022 day 1: Calorie Counting.
+
+use aoc_core::log::trace;
+use aoc_core::{ParseError, Rng, Solution, BOM};
+use serde::Serialize;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt::Write;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+
+pub mod stats;
+
+/// What an elf carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Load {
+    /// Position of the elf in the input, from 0.
+    pub elf: usize,
+    pub total: i64,
+    pub items: usize,
+}
+
+/// Orders loads by rank: heaviest first, the first elf first when they are equal.
+pub(crate) fn rank(load: &Load) -> (Reverse<i64>, usize, usize) {
+    (Reverse(load.total), load.elf, load.items)
+}
+
+/// A load compared by `rank`, the best ranked being the smallest.
+#[derive(Debug, PartialEq, Eq)]
+struct Ranked(Load);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        rank(&self.0).cmp(&rank(&other.0))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The load of every elf, in the input order.
+pub fn loads(elves: &[Vec<i64>]) -> impl Iterator<Item = Load> + '_ {
+    elves.iter().enumerate().map(|(elf, items)| Load {
+        elf,
+        total: items.iter().sum(),
+        items: items.len(),
+    })
+}
+
+/// Keeps the `k` heaviest of the loads pushed to it, in a heap whose top is
+/// the lightest so it is the one to go when there are more than `k`.
+pub struct Top {
+    k: usize,
+    heap: BinaryHeap<Ranked>,
+}
+
+impl Top {
+    pub fn new(k: usize) -> Self {
+        Top {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, load: Load) {
+        self.heap.push(Ranked(load));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The loads kept, heaviest first.
+    pub fn into_vec(self) -> Vec<Load> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| ranked.0)
+            .collect()
+    }
+}
+
+/// The `k` heaviest loads, heaviest first.
+pub fn top(elves: &[Vec<i64>], k: usize) -> Vec<Load> {
+    let mut top = Top::new(k);
+    loads(elves).for_each(|load| top.push(load));
+    top.into_vec()
+}
+
+/// The calories of an item, added to `total`, the calories of the whole
+/// inventory so far. Keeping it in an `i64` keeps every sum of loads in one.
+fn count_calories(text: &str, total: &mut i64) -> Result<i64, String> {
+    let calories: i64 = text.parse().map_err(|e: ParseIntError| e.to_string())?;
+    if calories < 0 {
+        return Err("calories can't be negative".to_string());
+    }
+    *total = total
+        .checked_add(calories)
+        .ok_or("the inventory has more calories than an i64 holds")?;
+    Ok(calories)
+}
+
+/// Reads the loads of the elves from an inventory one at a time, with a single
+/// line in memory, for inventories too big to be loaded at once. The lines are
+/// read as `normalize` and `blocks` would: the byte order mark of the first
+/// line and trailing whitespace are ignored and any run of blank lines
+/// separates two elves.
+pub struct LoadReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    elf: usize,
+    calories: i64,
+}
+
+impl<R: BufRead> LoadReader<R> {
+    pub fn new(reader: R) -> Self {
+        LoadReader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            elf: 0,
+            calories: 0,
+        }
+    }
+
+    fn read_load(&mut self) -> io::Result<Option<Load>> {
+        let mut load: Option<Load> = None;
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+            self.line_number += 1;
+            let mut text = self.line.trim_end();
+            if self.line_number == 1 {
+                text = text.strip_prefix(BOM).unwrap_or(text);
+            }
+            if text.is_empty() {
+                match load {
+                    Some(_) => break,
+                    None => continue,
+                }
+            }
+            let calories = count_calories(text, &mut self.calories).map_err(|e| {
+                let mut error = ParseError::at(text, text, e);
+                error.line = self.line_number;
+                io::Error::new(io::ErrorKind::InvalidData, error)
+            })?;
+            let load = load.get_or_insert(Load {
+                elf: self.elf,
+                total: 0,
+                items: 0,
+            });
+            load.total += calories;
+            load.items += 1;
+        }
+        if load.is_some() {
+            self.elf += 1;
+        }
+        Ok(load)
+    }
+}
+
+impl<R: BufRead> Iterator for LoadReader<R> {
+    type Item = io::Result<Load>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_load().transpose()
+    }
+}
+
+/// Both answers from a single pass over an inventory, in constant memory.
+pub fn solve_stream(reader: impl BufRead) -> io::Result<(i64, i64)> {
+    let mut top = Top::new(3);
+    for load in LoadReader::new(reader) {
+        top.push(load?);
+    }
+    let top = top.into_vec();
+    Ok((
+        top.first().map_or(0, |load| load.total),
+        top.iter().map(|load| load.total).sum(),
+    ))
+}
+
+/// Every load, heaviest first.
+pub fn ranking(elves: &[Vec<i64>]) -> Vec<Load> {
+    let mut ranking: Vec<Load> = loads(elves).collect();
+    ranking.sort_by_key(rank);
+    ranking
+}
+
+/// `ranking` as a table, one line per elf, elves numbered from 1.
+pub fn report(ranking: &[Load]) -> String {
+    let mut report = format!("{:>4} {:>5} {:>8} {:>5}\n", "Rank", "Elf", "Total", "Items");
+    for (rank, load) in ranking.iter().enumerate() {
+        writeln!(
+            report,
+            "{:>4} {:>5} {:>8} {:>5}",
+            rank + 1,
+            load.elf + 1,
+            load.total,
+            load.items
+        )
+        .unwrap();
+    }
+    report
+}
+
+fn top_total(elves: &[Vec<i64>], k: usize) -> i64 {
+    let top = top(elves, k);
+    trace!("top {}: {:?}", k, top);
+    top.iter().map(|load| load.total).sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<Vec<i64>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut total = 0;
+        aoc_core::blocks(input)
+            .map(|x| {
+                aoc_core::lines(x)
+                    .map(|y| count_calories(y, &mut total).map_err(|e| ParseError::at(input, y, e)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(elves: &Self::Input) -> i64 {
+        top_total(elves, 1)
+    }
+
+    fn part2(elves: &Self::Input) -> i64 {
+        top_total(elves, 3)
+    }
+
+    /// `size` elves carrying 1 to 15 items each.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let items: Vec<String> = (0..rng.range(1..16))
+                    .map(|_| rng.range(1000..60001).to_string())
+                    .collect();
+                items.join("\n")
+            })
+            .collect();
+        Some(elves.join("\n\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const INPUT: &str = include_str!("../inputs/sample.txt");
+
+    fn load(elf: usize, total: i64, items: usize) -> Load {
+        Load { elf, total, items }
+    }
+
+    #[test]
+    fn test_top() {
+        let elves = Day1::parse(INPUT).unwrap();
+        assert_eq!(top(&elves, 1), vec![load(3, 24000, 3)]);
+        assert_eq!(
+            top(&elves, 3),
+            vec![load(3, 24000, 3), load(2, 11000, 2), load(4, 10000, 1)]
+        );
+        assert_eq!(top(&elves, 10).len(), 5);
+        assert_eq!(top(&elves, 0), vec![]);
+    }
+    #[test]
+    fn test_ties() {
+        let elves = vec![vec![5], vec![2, 3], vec![1]];
+        assert_eq!(top(&elves, 1), vec![load(0, 5, 1)]);
+        assert_eq!(
+            ranking(&elves),
+            vec![load(0, 5, 1), load(1, 5, 2), load(2, 1, 1)]
+        );
+    }
+    #[test]
+    fn test_report() {
+        let elves = Day1::parse(INPUT).unwrap();
+        assert_eq!(
+            report(&ranking(&elves)[..2]),
+            "Rank   Elf    Total Items\n   1     4    24000     3\n   2     3    11000     2\n"
+        );
+    }
+    #[test]
+    fn test_stream() {
+        assert_eq!(solve_stream(INPUT.as_bytes()).unwrap(), (24000, 45000));
+        let messy = "\u{feff}1000\r\n2000 \r\n\r\n\r\n\r\n3000\n\n";
+        let loads: Vec<Load> = LoadReader::new(messy.as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(loads, vec![load(0, 3000, 2), load(1, 3000, 1)]);
+        let elves = Day1::parse(&aoc_core::normalize(messy)).unwrap();
+        assert_eq!(crate::loads(&elves).collect::<Vec<_>>(), loads);
+
+        let error = solve_stream("1000\n\n2x00\n".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: invalid digit found in string\n3 | 2x00\n  | ^"
+        );
+        let error = solve_stream("1000\n\u{feff}2000\n".as_bytes()).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("line 2, column 1: invalid digit"));
+    }
+    #[test]
+    fn reject_overflow() {
+        let error = Day1::parse("9223372036854775807\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            error.message,
+            "the inventory has more calories than an i64 holds"
+        );
+        assert!(solve_stream("9223372036854775807\n1\n".as_bytes()).is_err());
+        let error = Day1::parse("1\n-2\n").unwrap_err();
+        assert_eq!(error.message, "calories can't be negative");
+    }
+    #[test]
+    fn test_parts() {
+        let elves = Day1::parse(INPUT).unwrap();
+        assert_eq!(Day1::part1(&elves), 24000);
+        assert_eq!(Day1::part2(&elves), 45000);
+    }
+}
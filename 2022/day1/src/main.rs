@@ -1,46 +1,57 @@
-use std::fs;
-use std::mem;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::process;
 
-fn main() {
-    let input = fs::read_to_string("inputs.txt").expect("Impossible to read file");
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+use aoc_core::{normalize, InputSource, Solution};
+use day1::{stats, Day1};
+
+/// Solves an inventory too big to be loaded in memory, `-` being stdin.
+fn stream(path: &str) -> Result<(), String> {
+    let reader: Box<dyn BufRead> = match path {
+        "-" => Box::new(io::stdin().lock()),
+        path => Box::new(BufReader::new(
+            File::open(path).map_err(|e| format!("{}: {}", path, e))?,
+        )),
+    };
+    let (part1, part2) = day1::solve_stream(reader).map_err(|e| format!("{}: {}", path, e))?;
+    println!("Part 1: {}", part1);
+    println!("Part 2: {}", part2);
+    Ok(())
 }
 
-fn part1(input: &str) -> i64 {
-    let splited_str = input.trim().split("\n\n");
-    splited_str
-        .into_iter()
-        .map(|x| {
-            x.trim()
-                .split('\n')
-                .into_iter()
-                .map(|y| y.parse::<i64>().unwrap())
-                .sum::<i64>()
-        })
-        .max()
-        .unwrap()
+/// Prints the statistics of an input (the real one by default), as JSON with `--json`.
+fn print_stats(args: &[String]) -> Result<(), String> {
+    let json = args.iter().any(|arg| arg == "--json");
+    let source = match args.iter().find(|arg| *arg != "--json") {
+        Some(arg) => InputSource::from(arg.as_str()),
+        None => InputSource::default(),
+    };
+    let input = normalize(&source.read(Day1::INPUTS_DIR)?);
+    let elves = Day1::parse(&input).map_err(|e| e.to_string())?;
+    let stats = stats::stats(&elves).ok_or("There are no elves in the input")?;
+    match json {
+        true => println!(
+            "{}",
+            serde_json::to_string_pretty(&stats).expect("Stats are always serializable")
+        ),
+        false => println!("{}", stats),
+    }
+    Ok(())
 }
 
-fn part2(input: &str) -> i64 {
-    let mut top = vec![0; 3];
-    let splited_str = input.trim().split("\n\n");
-    let sums = splited_str.into_iter().map(|x| {
-        x.trim()
-            .split('\n')
-            .into_iter()
-            .map(|y| y.parse::<i64>().unwrap())
-            .sum::<i64>()
-    });
-    for sum in sums {
-        if sum > *top.iter().min().unwrap() {
-            let min_pos = top
-                .iter()
-                .position(|x| *x == *top.iter().min().unwrap())
-                .unwrap();
-            let _ = mem::replace(&mut top[min_pos], sum);
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match &args[..] {
+        [flag, path] if flag == "--stream" => stream(path),
+        [mode, rest @ ..] if mode == "stats" => print_stats(rest),
+        _ => {
+            aoc_core::main::<Day1>();
+            Ok(())
         }
-        println!("{:?}", top)
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
     }
-    top.into_iter().sum()
 }
@@ -0,0 +1,224 @@
+//! Statistics on the loads of the elves, beyond what the puzzle asks.
+
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::{loads, rank, Load};
+
+/// Percentiles given in the report.
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Maximum number of buckets of the histogram.
+const BUCKETS: i64 = 10;
+
+/// Width of the longest bar of the histogram.
+const BAR_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Percentile {
+    pub percentile: u8,
+    pub total: i64,
+}
+
+/// Elves whose load is in `start..end`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Bucket {
+    pub start: i64,
+    pub end: i64,
+    pub elves: usize,
+}
+
+/// Distribution of the loads of the elves. Elves are numbered from 0 like
+/// in `Load`. There are no empty packs to report: blank lines only separate
+/// elves, so every elf of an inventory carries at least one item.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub single_item: Vec<usize>,
+    /// Loads further than 1.5 times the interquartile range from the first or
+    /// third quartile (Tukey's fences), heaviest first.
+    pub outliers: Vec<Load>,
+}
+
+/// The total at `percentile` of the sorted `totals`, by the nearest rank method.
+fn percentile(totals: &[i64], percentile: u8) -> i64 {
+    let rank = (percentile as usize * totals.len()).div_ceil(100);
+    totals[rank.max(1) - 1]
+}
+
+fn histogram(totals: &[i64]) -> Vec<Bucket> {
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+    // The smallest width fitting max - min + 1 totals in BUCKETS, without overflowing
+    let width = (max - min) / BUCKETS + 1;
+    let mut buckets: Vec<Bucket> = (min..=max)
+        .step_by(width as usize)
+        .map(|start| Bucket {
+            start,
+            end: start.saturating_add(width),
+            elves: 0,
+        })
+        .collect();
+    for total in totals {
+        buckets[((total - min) / width) as usize].elves += 1;
+    }
+    buckets
+}
+
+/// `None` when there are no elves to describe.
+pub fn stats(elves: &[Vec<i64>]) -> Option<Stats> {
+    let mut totals: Vec<i64> = loads(elves).map(|load| load.total).collect();
+    if totals.is_empty() {
+        return None;
+    }
+    totals.sort();
+    let count = totals.len();
+    let median = match count % 2 {
+        0 => (totals[count / 2 - 1] + totals[count / 2]) as f64 / 2.0,
+        _ => totals[count / 2] as f64,
+    };
+    let (q1, q3) = (percentile(&totals, 25), percentile(&totals, 75));
+    let fence = 1.5 * (q3 - q1) as f64;
+    let is_outlier =
+        |total: i64| (total as f64) < q1 as f64 - fence || total as f64 > q3 as f64 + fence;
+    let mut outliers: Vec<Load> = loads(elves).filter(|load| is_outlier(load.total)).collect();
+    outliers.sort_by_key(rank);
+    let with_items = |items: usize| {
+        loads(elves)
+            .filter(|load| load.items == items)
+            .map(|load| load.elf)
+            .collect()
+    };
+    Some(Stats {
+        elves: count,
+        items: elves.iter().map(Vec::len).sum(),
+        mean: totals.iter().sum::<i64>() as f64 / count as f64,
+        median,
+        percentiles: PERCENTILES
+            .iter()
+            .map(|p| Percentile {
+                percentile: *p,
+                total: percentile(&totals, *p),
+            })
+            .collect(),
+        histogram: histogram(&totals),
+        single_item: with_items(1),
+        outliers,
+    })
+}
+
+/// Elves numbered from 1, like in the puzzle text.
+fn elf_list(elves: &[usize]) -> String {
+    match elves {
+        [] => "none".to_string(),
+        _ => {
+            let numbers: Vec<String> = elves.iter().map(|elf| (elf + 1).to_string()).collect();
+            format!("{} (elves {})", elves.len(), numbers.join(", "))
+        }
+    }
+}
+
+/// The report for humans.
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {} carrying {} items", self.elves, self.items)?;
+        writeln!(f, "Mean: {:.1}, median: {:.1}", self.mean, self.median)?;
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|p| format!("p{} {}", p.percentile, p.total))
+            .collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+        writeln!(f, "Histogram:")?;
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            let bar = "#".repeat((bucket.elves * BAR_WIDTH).div_ceil(most.max(1)));
+            writeln!(
+                f,
+                "  {:>8} - {:<8} {:<width$} {}",
+                bucket.start,
+                bucket.end - 1,
+                bar,
+                bucket.elves,
+                width = BAR_WIDTH
+            )?;
+        }
+        writeln!(f, "Single item packs: {}", elf_list(&self.single_item))?;
+        let outliers: Vec<String> = self
+            .outliers
+            .iter()
+            .map(|load| format!("elf {} ({})", load.elf + 1, load.total))
+            .collect();
+        match outliers.is_empty() {
+            true => write!(f, "Outliers: none"),
+            false => write!(f, "Outliers: {}", outliers.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stats::*;
+    use crate::Day1;
+    use aoc_core::Solution;
+
+    const INPUT: &str = include_str!("../inputs/sample.txt");
+
+    #[test]
+    fn test_stats() {
+        let stats = stats(&Day1::parse(INPUT).unwrap()).unwrap();
+        assert_eq!((stats.elves, stats.items), (5, 10));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(
+            stats
+                .percentiles
+                .iter()
+                .map(|p| p.total)
+                .collect::<Vec<_>>(),
+            vec![4000, 6000, 11000, 24000, 24000]
+        );
+        assert_eq!(stats.histogram.len(), 10);
+        assert_eq!(
+            stats.histogram[0],
+            Bucket {
+                start: 4000,
+                end: 6001,
+                elves: 2
+            }
+        );
+        assert_eq!(stats.histogram.iter().map(|b| b.elves).sum::<usize>(), 5);
+        assert_eq!(stats.single_item, vec![1, 4]);
+        assert_eq!(
+            stats.outliers,
+            vec![Load {
+                elf: 3,
+                total: 24000,
+                items: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_outliers() {
+        let elves = vec![
+            vec![10],
+            vec![11],
+            vec![12],
+            vec![13],
+            vec![100, 900],
+            vec![2],
+        ];
+        let stats = stats(&elves).unwrap();
+        let outliers: Vec<(usize, i64)> = stats.outliers.iter().map(|l| (l.elf, l.total)).collect();
+        assert_eq!(outliers, vec![(4, 1000), (5, 2)]);
+        assert!(stats
+            .to_string()
+            .ends_with("Outliers: elf 5 (1000), elf 6 (2)"));
+        assert_eq!(super::stats(&[]), None);
+    }
+}
@@ -0,0 +1,314 @@
+//! 2022 day 10: Cathode-Ray Tube.
+
+use aoc_core::log::{debug, trace};
+use aoc_core::parse::{self, signed, token, PResult};
+use aoc_core::{Grid, ParseError, Rng, Solution};
+
+use nom::{branch::alt, combinator::map, sequence::preceded};
+
+#[derive(Debug, Clone)]
+struct Noop;
+fn parse_noop(input: &str) -> PResult<'_, Noop> {
+    map(token("noop"), |_| Noop)(input)
+}
+
+#[derive(Debug, Clone)]
+struct Addx(i32);
+fn parse_add(input: &str) -> PResult<'_, Addx> {
+    map(preceded(token("addx "), signed), Addx)(input)
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+impl From<Noop> for Instruction {
+    fn from(_noop: Noop) -> Self {
+        Instruction::Noop
+    }
+}
+impl From<Addx> for Instruction {
+    fn from(_add: Addx) -> Self {
+        Instruction::Addx(_add.0)
+    }
+}
+
+pub fn parse_line(input: &str) -> PResult<'_, Instruction> {
+    alt((map(parse_noop, Into::into), map(parse_add, Into::into)))(input)
+}
+
+/// Value of the X register at the end of every cycle, or the index of the
+/// instruction that overflows it.
+pub fn get_x_per_cycle(instructions: &[Instruction]) -> Result<Vec<i32>, usize> {
+    let mut x_per_cycle: Vec<i32> = vec![];
+    let mut x: i32 = 1;
+    for (idx, ins) in instructions.iter().enumerate() {
+        x_per_cycle.push(x);
+        match ins {
+            Instruction::Noop => {}
+            Instruction::Addx(v) => {
+                x = x.checked_add(*v).ok_or(idx)?;
+                x_per_cycle.push(x);
+            }
+        }
+    }
+    Ok(x_per_cycle)
+}
+
+/// Cycles needed to draw the whole screen, which also covers the signal strengths.
+pub const SCREEN_CYCLES: usize = 240;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<Instruction>;
+    type Output1 = i64;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let instructions = parse::lines(input, parse_line)?;
+        let cycles = get_x_per_cycle(&instructions)
+            .map_err(|idx| {
+                let line = aoc_core::lines(input)
+                    .nth(idx)
+                    .expect("One line per instruction");
+                ParseError::at(input, line, "X overflows 32 bits")
+            })?
+            .len();
+        if cycles < SCREEN_CYCLES {
+            return Err(ParseError::after(
+                input,
+                input.trim_end(),
+                format!("program runs {} cycles, {} needed", cycles, SCREEN_CYCLES),
+            ));
+        }
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Input) -> i64 {
+        let x_per_cycle = get_x_per_cycle(instructions).expect("Checked by the parser");
+        let cycles: Vec<usize> = vec![20, 60, 100, 140, 180, 220];
+        let mut sig_str_sum = 0;
+        for cycle in cycles {
+            debug!("cycle {}, x: {}", cycle, x_per_cycle[cycle - 2]);
+            sig_str_sum += cycle as i64 * x_per_cycle[cycle - 2] as i64;
+        }
+        sig_str_sum
+    }
+
+    fn part2(instructions: &Self::Input) -> String {
+        let mut screen = Grid::new(40, 6, '.');
+        let x_per_cycle = get_x_per_cycle(instructions).expect("Checked by the parser");
+        for line in 0..6 {
+            for row in 0..40 {
+                let lit = if line == 0 && row == 0 {
+                    true
+                } else {
+                    let idx = 40 * line + row;
+                    trace!("idx: {}, x: {}", idx, x_per_cycle[idx]);
+                    (row as i64 - x_per_cycle[idx - 1] as i64).abs() <= 1
+                };
+                if lit {
+                    screen[(line, row)] = '#';
+                }
+            }
+        }
+        screen.to_string()
+    }
+
+    /// At least `size` instructions, and enough to draw the whole screen. The
+    /// sprite stays around the screen so the picture is not blank.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (mut program, mut count, mut cycles, mut x) = (String::new(), 0, 0, 1);
+        while count < size || cycles < SCREEN_CYCLES {
+            if rng.chance(1, 3) {
+                program += "noop\n";
+                cycles += 1;
+            } else {
+                let value = rng.range((-1 - x).max(-15)..(40 - x).min(15) + 1);
+                program += &format!("addx {}\n", value);
+                cycles += 2;
+                x += value;
+            }
+            count += 1;
+        }
+        Some(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const INPUT: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day10::part1(&Day10::parse(INPUT).unwrap()), 13140);
+    }
+
+    #[test]
+    fn test_part2() {
+        aoc_core::assert_snapshot!("sample_part2", Day10::part2(&Day10::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn reject_short() {
+        let error = Day10::parse("noop\naddx 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.message, "program runs 3 cycles, 240 needed");
+        let error = Day10::parse("noop\naddx 2147483646\naddx 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "X overflows 32 bits");
+    }
+}
@@ -0,0 +1,346 @@
+//! 2022 day 11: Monkey in the Middle.
+
+use aoc_core::log::debug;
+use aoc_core::parse::{self, key_value, token, unsigned, PResult};
+use aoc_core::{ParseError, Rng, Solution};
+use std::cell::RefCell;
+
+use nom::{
+    branch::alt,
+    character::complete::{line_ending, space0},
+    combinator::{consumed, map, value, verify},
+    sequence::{preceded, terminated, tuple},
+};
+
+#[derive(Debug, Clone)]
+pub enum Op {
+    Add(u64),
+    Mult(u64),
+    Square,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: RefCell<Vec<u64>>,
+    pub op: Op,
+    pub test: u64,
+    pub test_pass_monkey: usize,
+    pub test_fail_monkey: usize,
+}
+
+fn operation(s: &str) -> PResult<'_, Op> {
+    parse::expected(
+        "expected `+ <number>`, `* <number>` or `* old`",
+        alt((
+            value(Op::Square, token("* old")),
+            map(preceded(token("+ "), unsigned), Op::Add),
+            map(preceded(token("* "), unsigned), Op::Mult),
+        )),
+    )(s)
+}
+
+/// A monkey, along with the text of its divisor and of the monkeys it throws
+/// to so they can be checked once every monkey is known.
+fn monkey(s: &str) -> PResult<'_, (Monkey, [&str; 3])> {
+    let divisor = parse::expected(
+        "cannot test divisibility by 0",
+        verify(unsigned, |test: &u64| *test != 0),
+    );
+    map(
+        tuple((
+            key_value("Monkey ", terminated(unsigned::<usize>, token(":"))),
+            preceded(
+                line_ending,
+                key_value(
+                    "Starting items:",
+                    preceded(space0, parse::list(unsigned, ", ")),
+                ),
+            ),
+            preceded(line_ending, key_value("Operation: new = old ", operation)),
+            preceded(
+                line_ending,
+                key_value("Test: divisible by ", consumed(divisor)),
+            ),
+            preceded(
+                line_ending,
+                key_value("If true: throw to monkey ", consumed(unsigned)),
+            ),
+            preceded(
+                line_ending,
+                key_value("If false: throw to monkey ", consumed(unsigned)),
+            ),
+        )),
+        |(
+            _,
+            items,
+            op,
+            (test_text, test),
+            (pass_text, test_pass_monkey),
+            (fail_text, test_fail_monkey),
+        )| {
+            (
+                Monkey {
+                    items: RefCell::new(items),
+                    op,
+                    test,
+                    test_pass_monkey,
+                    test_fail_monkey,
+                },
+                [test_text, pass_text, fail_text],
+            )
+        },
+    )(s)
+}
+
+/// Rounds played in part 1.
+pub const PART1_ROUNDS: usize = 20;
+/// Rounds played in part 2.
+pub const PART2_ROUNDS: usize = 10000;
+
+/// Product of the divisors of the monkeys, which every worry level can be
+/// taken modulo. `None` if it doesn't fit in a `u64`.
+pub fn modulo(monkeys: &[Monkey]) -> Option<u64> {
+    monkeys
+        .iter()
+        .try_fold(1_u64, |product, monkey| product.checked_mul(monkey.test))
+}
+
+/// Every monkey, checking that they only throw to other monkeys that exist and
+/// that both parts can be played without overflowing.
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = parse::blocks(input, monkey)?;
+    let count = monkeys.len();
+    let mut product: u64 = 1;
+    let monkeys = monkeys
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (monkey, [test_text, pass_text, fail_text]))| {
+            product = product.checked_mul(monkey.test).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    test_text,
+                    "the product of the divisors doesn't fit in 64 bits",
+                )
+            })?;
+            let throws = [monkey.test_pass_monkey, monkey.test_fail_monkey];
+            for (target, text) in throws.into_iter().zip([pass_text, fail_text]) {
+                if target >= count {
+                    return Err(ParseError::at(
+                        input,
+                        text,
+                        format!("there are only {} monkeys", count),
+                    ));
+                }
+                if target == idx {
+                    return Err(ParseError::at(
+                        input,
+                        text,
+                        "a monkey can't throw to itself",
+                    ));
+                }
+            }
+            Ok(monkey)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let end = |message| Err(ParseError::after(input, input.trim_end(), message));
+    // Every inspection is counted, the monkey business multiplies two counts
+    let items: usize = monkeys.iter().map(|m| m.items.borrow().len()).sum();
+    let most = (PART2_ROUNDS as u64).checked_mul(items as u64);
+    if most.and_then(|most| most.checked_mul(most)).is_none() {
+        return end("too many items, the monkey business would overflow");
+    }
+    // Part 2 keeps worry levels below the product of the divisors, but nothing
+    // bounds them in part 1 besides playing it
+    if solve(&mut monkeys.clone(), |x| x / 3, PART1_ROUNDS).is_none() {
+        return end("worry levels overflow 64 bits in part 1");
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<Monkey>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> u64 {
+        solve(&mut monkeys.clone(), |x| x / 3, PART1_ROUNDS).expect("Checked by the parser")
+    }
+
+    fn part2(monkeys: &Self::Input) -> u64 {
+        let modulo = modulo(monkeys).expect("Checked by the parser") as u128;
+        solve(&mut monkeys.clone(), |x| x % modulo, PART2_ROUNDS).expect("Checked by the parser")
+    }
+
+    /// `size` monkeys, between 2 and 8 so worry levels fit in a `u64`. To keep
+    /// them from growing in part 1, nobody throws to the monkey squaring them
+    /// and the (at most 2) monkeys multiplying them only throw to monkeys
+    /// adding to them. Divisors are distinct primes, like in the real inputs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let count = size.clamp(2, 8);
+        let mut ops: Vec<Op> = (0..count)
+            .map(|_| Op::Add(rng.range(1..9) as u64))
+            .collect();
+        if count >= 3 {
+            ops[0] = Op::Square;
+            ops[1] = Op::Mult(rng.range(2..20) as u64);
+        }
+        if count >= 4 {
+            ops[2] = Op::Mult(rng.range(2..20) as u64);
+        }
+        rng.shuffle(&mut ops);
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+
+        let mut monkeys = vec![];
+        for (idx, op) in ops.iter().enumerate() {
+            let targets: Vec<usize> = (0..count)
+                .filter(|target| *target != idx)
+                .filter(|target| match (op, &ops[*target]) {
+                    (_, Op::Square) => false,
+                    (Op::Mult(_), other) => matches!(other, Op::Add(_)),
+                    _ => true,
+                })
+                .collect();
+            let items: Vec<String> = (0..rng.range(1..5))
+                .map(|_| rng.range(50..100).to_string())
+                .collect();
+            let op = match op {
+                Op::Add(value) => format!("+ {}", value),
+                Op::Mult(value) => format!("* {}", value),
+                Op::Square => "* old".to_string(),
+            };
+            monkeys.push(format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                idx,
+                items.join(", "),
+                op,
+                primes[idx],
+                rng.choose(&targets),
+                rng.choose(&targets),
+            ));
+        }
+        Some(monkeys.join("\n"))
+    }
+}
+
+/// Plays `rounds` rounds, `worry_fn` being applied after each inspection, and
+/// returns the monkey business. `None` if a worry level doesn't fit in a `u64`
+/// after `worry_fn`, or the monkey business doesn't.
+pub fn solve(
+    monkeys: &mut [Monkey],
+    worry_fn: impl Fn(u128) -> u128,
+    rounds: usize,
+) -> Option<u64> {
+    let mut inspected = vec![0_u64; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in monkey.items.borrow().iter() {
+                inspected[i] += 1;
+                // Operations on `u64`s can't overflow a `u128`
+                let item = *item as u128;
+                let new_item = match monkey.op {
+                    Op::Add(o) => item + o as u128,
+                    Op::Mult(o) => item * o as u128,
+                    Op::Square => item * item,
+                };
+                let new_item = u64::try_from(worry_fn(new_item)).ok()?;
+                if new_item.is_multiple_of(monkey.test) {
+                    monkeys[monkey.test_pass_monkey]
+                        .items
+                        .borrow_mut()
+                        .push(new_item);
+                } else {
+                    monkeys[monkey.test_fail_monkey]
+                        .items
+                        .borrow_mut()
+                        .push(new_item);
+                }
+            }
+            monkey.items.borrow_mut().clear();
+        }
+    }
+    inspected.sort();
+    debug!("inspected: {:?}", inspected);
+    inspected
+        .iter()
+        .rev()
+        .take(2)
+        .try_fold(1_u64, |business, count| business.checked_mul(*count))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            solve(&mut parse_input(INPUT).unwrap(), |x| x / 3, 20),
+            Some(10605)
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut monkeys = parse_input(INPUT).unwrap();
+        let modulo = modulo(&monkeys).unwrap() as u128;
+        assert_eq!(solve(&mut monkeys, |x| x % modulo, 10000), Some(2713310158));
+    }
+
+    #[test]
+    fn reject_monkeys() {
+        let error =
+            parse_input(&INPUT.replace("throw to monkey 2", "throw to monkey 0")).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 30));
+        assert_eq!(error.message, "a monkey can't throw to itself");
+
+        let huge = INPUT
+            .replace("divisible by 19", "divisible by 4294967311")
+            .replace("divisible by 13", "divisible by 4294967357");
+        let error = parse_input(&huge).unwrap_err();
+        assert_eq!((error.line, error.column), (18, 22));
+
+        let error = parse_input(&INPUT.replace("old + 6", "old * old")).unwrap_err();
+        assert_eq!(error.message, "worry levels overflow 64 bits in part 1");
+    }
+}
@@ -0,0 +1,244 @@
+//! 2022 day 2: Rock Paper Scissors.
+
+pub mod strategy;
+
+use aoc_core::parse::{self, PResult};
+use aoc_core::{ParseError, Rng, Solution};
+use std::str::FromStr;
+
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map, map_opt},
+    sequence::{pair, preceded},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// The second column of the strategy guide, whose meaning is what the parts
+/// disagree on, see `strategy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+/// Which shape defeats which: `(winner, loser)`.
+pub const RULES: [(Shape, Shape); 3] = [
+    (Shape::Rock, Shape::Scissors),
+    (Shape::Paper, Shape::Rock),
+    (Shape::Scissors, Shape::Paper),
+];
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The outcome of playing `self` against `other`.
+    pub fn against(self, other: Shape) -> Outcome {
+        if self == other {
+            Outcome::Draw
+        } else if RULES.contains(&(self, other)) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The shape that defeats `self`.
+    pub fn defeated_by(self) -> Shape {
+        RULES
+            .iter()
+            .find(|(_, loser)| *loser == self)
+            .expect("Every shape is defeated by another")
+            .0
+    }
+
+    /// The shape to play against `other` to get `outcome`.
+    pub fn for_outcome(other: Shape, outcome: Outcome) -> Shape {
+        *Shape::ALL
+            .iter()
+            .find(|shape| shape.against(other) == outcome)
+            .expect("Every outcome is reachable against every shape")
+    }
+
+    pub fn score(self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+}
+
+impl Outcome {
+    pub fn score(self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// Score of a round where the player plays `player` against `elf`.
+pub fn score(elf: Shape, player: Shape) -> u32 {
+    player.score() + player.against(elf).score()
+}
+
+/// One line of the strategy guide.
+#[derive(Debug, PartialEq)]
+pub struct Round {
+    pub elf: Shape,
+    pub response: Response,
+}
+
+impl Round {
+    pub fn parse(s: &str) -> PResult<'_, Self> {
+        map(
+            pair(
+                column('A', Shape::ALL),
+                preceded(
+                    char(' '),
+                    column('X', [Response::X, Response::Y, Response::Z]),
+                ),
+            ),
+            |(elf, response)| Round { elf, response },
+        )(s)
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_all(s.trim(), Round::parse)
+    }
+}
+
+/// Decodes one column of the strategy guide, `first` being the letter of the
+/// first of `values`.
+fn column<'a, T: Copy>(first: char, values: [T; 3]) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    let letters: String = (first..).take(3).collect();
+    parse::expected(
+        format!("expected one of {}", letters),
+        map_opt(anychar, move |c| {
+            let idx = (c as u32).checked_sub(first as u32)?;
+            values.get(idx as usize).copied()
+        }),
+    )
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<Round>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, Round::parse)
+    }
+
+    fn part1(rounds: &Self::Input) -> u32 {
+        strategy::score(rounds, &strategy::PART1)
+    }
+
+    fn part2(rounds: &Self::Input) -> u32 {
+        strategy::score(rounds, &strategy::PART2)
+    }
+
+    /// A strategy guide of `size` rounds.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rounds: String = (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect();
+        Some(rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// `elf` and `response` as their letters in the guide.
+    fn round(elf: char, response: char) -> Vec<Round> {
+        vec![Round::from_str(&format!("{} {}", elf, response)).unwrap()]
+    }
+
+    #[test]
+    fn create_round() {
+        assert_eq!(
+            Round::from_str("A Y").unwrap(),
+            Round {
+                elf: Shape::Rock,
+                response: Response::Y
+            }
+        )
+    }
+    #[test]
+    fn reject_round() {
+        assert_eq!(Round::from_str("A W").unwrap_err().column, 3);
+        assert_eq!(Round::from_str("D X").unwrap_err().column, 1);
+        assert_eq!(Round::from_str("A").unwrap_err().column, 2);
+        assert_eq!(
+            Round::from_str("A [").unwrap_err().message,
+            "expected one of XYZ"
+        );
+    }
+    #[test]
+    fn test_rules() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.against(shape), Outcome::Draw);
+            for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                assert_eq!(Shape::for_outcome(shape, outcome).against(shape), outcome);
+            }
+        }
+        assert_eq!(Shape::Rock.against(Shape::Paper), Outcome::Loss);
+        assert_eq!(Shape::Rock.against(Shape::Scissors), Outcome::Win);
+    }
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day2::part1(&round('A', 'X')), 4);
+        assert_eq!(Day2::part1(&round('B', 'X')), 1);
+        assert_eq!(Day2::part1(&round('C', 'X')), 7);
+        assert_eq!(Day2::part1(&round('A', 'Y')), 8);
+        assert_eq!(Day2::part1(&round('B', 'Y')), 5);
+        assert_eq!(Day2::part1(&round('C', 'Y')), 2);
+        assert_eq!(Day2::part1(&round('A', 'Z')), 3);
+        assert_eq!(Day2::part1(&round('B', 'Z')), 9);
+        assert_eq!(Day2::part1(&round('C', 'Z')), 6);
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day2::part2(&round('A', 'Y')), 4);
+        assert_eq!(Day2::part2(&round('B', 'Y')), 5);
+        assert_eq!(Day2::part2(&round('C', 'Y')), 6);
+        assert_eq!(Day2::part2(&round('A', 'X')), 3);
+        assert_eq!(Day2::part2(&round('B', 'X')), 1);
+        assert_eq!(Day2::part2(&round('C', 'X')), 2);
+        assert_eq!(Day2::part2(&round('A', 'Z')), 8);
+        assert_eq!(Day2::part2(&round('B', 'Z')), 9);
+        assert_eq!(Day2::part2(&round('C', 'Z')), 7);
+    }
+}
@@ -0,0 +1,232 @@
+//! Interpretations of the second column of the strategy guide. The two parts
+//! are only two of them, any other is scored the same way.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::{Outcome, Response, Round, Shape};
+
+/// A reading of the second column: what X, Y and Z tell the player to play
+/// against the elf's shape.
+pub trait Decoding {
+    fn play(&self, elf: Shape, response: Response) -> Shape;
+}
+
+/// Any function of the elf's shape and the response is a decoding.
+impl<F: Fn(Shape, Response) -> Shape> Decoding for F {
+    fn play(&self, elf: Shape, response: Response) -> Shape {
+        self(elf, response)
+    }
+}
+
+/// The shape to play for each response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shapes(pub [Shape; 3]);
+
+impl Decoding for Shapes {
+    fn play(&self, _elf: Shape, response: Response) -> Shape {
+        self.0[response as usize]
+    }
+}
+
+/// How the round must end for each response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcomes(pub [Outcome; 3]);
+
+impl Decoding for Outcomes {
+    fn play(&self, elf: Shape, response: Response) -> Shape {
+        Shape::for_outcome(elf, self.0[response as usize])
+    }
+}
+
+/// How many times to go from the elf's shape to the one defeating it for each
+/// response: 0 draws, 1 wins and 2 loses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shift(pub [u8; 3]);
+
+impl Decoding for Shift {
+    fn play(&self, elf: Shape, response: Response) -> Shape {
+        (0..self.0[response as usize]).fold(elf, |shape, _| shape.defeated_by())
+    }
+}
+
+/// The response is the shape to play.
+pub const PART1: Shapes = Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]);
+/// The response is how the round must end.
+pub const PART2: Outcomes = Outcomes([Outcome::Loss, Outcome::Draw, Outcome::Win]);
+
+/// Total score of following the guide with `decoding`.
+pub fn score(rounds: &[Round], decoding: &dyn Decoding) -> u32 {
+    rounds
+        .iter()
+        .map(|r| crate::score(r.elf, decoding.play(r.elf, r.response)))
+        .sum()
+}
+
+/// Builds a decoding of a kind from the letters given after `<kind>:`.
+pub type Parser = fn(&str) -> Result<Arc<dyn Decoding>, String>;
+
+/// The decodings that can be selected by name, and the kinds of decodings that
+/// can be built from letters.
+pub struct Registry {
+    named: BTreeMap<String, Arc<dyn Decoding>>,
+    kinds: BTreeMap<String, Parser>,
+}
+
+impl Registry {
+    /// A registry without any decoding.
+    pub fn new() -> Self {
+        Registry {
+            named: BTreeMap::new(),
+            kinds: BTreeMap::new(),
+        }
+    }
+
+    /// Makes `decoding` selectable as `name`, replacing any decoding of that name.
+    pub fn register(&mut self, name: &str, decoding: impl Decoding + 'static) {
+        self.named.insert(name.to_string(), Arc::new(decoding));
+    }
+
+    /// Makes decodings selectable as `<kind>:<letters>`, built by `parser`.
+    pub fn register_kind(&mut self, kind: &str, parser: Parser) {
+        self.kinds.insert(kind.to_string(), parser);
+    }
+
+    /// The decoding registered as `name`, or built from `<kind>:<letters>`.
+    pub fn get(&self, spec: &str) -> Result<Arc<dyn Decoding>, String> {
+        if let Some(decoding) = self.named.get(spec) {
+            return Ok(decoding.clone());
+        }
+        match spec.split_once(':') {
+            Some((kind, letters)) if self.kinds.contains_key(kind) => self.kinds[kind](letters),
+            _ => {
+                let names: Vec<&str> = self.named.keys().map(String::as_str).collect();
+                let kinds: Vec<String> = self.kinds.keys().map(|k| format!("{}:", k)).collect();
+                Err(format!(
+                    "Unknown strategy `{}`, expected one of {} or one of {} followed by letters",
+                    spec,
+                    names.join(", "),
+                    kinds.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+/// The built-in decodings: `part1` and `part2`, and `shapes:`, `outcomes:`
+/// and `shift:` followed by the letters for X, Y and Z: `R`, `P` or `S` for
+/// shapes, `L`, `D` or `W` for outcomes and `0`, `1` or `2` for shifts. E.g.
+/// `part2` is `outcomes:LDW`.
+impl Default for Registry {
+    fn default() -> Self {
+        let mut registry = Registry::new();
+        registry.register("part1", PART1);
+        registry.register("part2", PART2);
+        registry.register_kind("shapes", |letters| {
+            Ok(Arc::new(Shapes(decode(letters, &SHAPES)?)))
+        });
+        registry.register_kind("outcomes", |letters| {
+            Ok(Arc::new(Outcomes(decode(letters, &OUTCOMES)?)))
+        });
+        registry.register_kind("shift", |letters| {
+            Ok(Arc::new(Shift(decode(letters, &STEPS)?)))
+        });
+        registry
+    }
+}
+
+/// The letter of each shape, outcome and number of steps in strategies.
+const SHAPES: [(char, Shape); 3] = [
+    ('R', Shape::Rock),
+    ('P', Shape::Paper),
+    ('S', Shape::Scissors),
+];
+const OUTCOMES: [(char, Outcome); 3] = [
+    ('L', Outcome::Loss),
+    ('D', Outcome::Draw),
+    ('W', Outcome::Win),
+];
+const STEPS: [(char, u8); 3] = [('0', 0), ('1', 1), ('2', 2)];
+
+/// Decodes the three `letters` given for X, Y and Z.
+fn decode<T: Copy>(letters: &str, values: &[(char, T); 3]) -> Result<[T; 3], String> {
+    let decoded: Vec<T> = letters
+        .chars()
+        .map(|c| {
+            values
+                .iter()
+                .find(|(letter, _)| *letter == c)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| {
+                    let letters: String = values.iter().map(|(letter, _)| letter).collect();
+                    format!("`{}` is not one of {}", c, letters)
+                })
+        })
+        .collect::<Result<_, _>>()?;
+    decoded.try_into().map_err(|_| {
+        format!(
+            "Expected 3 letters, one for each of X, Y and Z, got `{}`",
+            letters
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strategy::*;
+    use crate::Day2;
+    use aoc_core::Solution;
+
+    const INPUT: &str = include_str!("../inputs/sample.txt");
+
+    fn total(spec: &str) -> Result<u32, String> {
+        let rounds = Day2::parse(INPUT).unwrap();
+        Ok(score(&rounds, &*Registry::default().get(spec)?))
+    }
+
+    #[test]
+    fn test_registry() {
+        assert_eq!(total("part1"), Ok(15));
+        assert_eq!(total("part2"), Ok(12));
+        assert_eq!(total("shapes:RPS"), Ok(15));
+        assert_eq!(total("outcomes:LDW"), Ok(12));
+        assert_eq!(total("shift:30"), Err("`3` is not one of 012".to_string()));
+        assert!(total("shift:01")
+            .unwrap_err()
+            .starts_with("Expected 3 letters"));
+        assert_eq!(
+            total("cheat"),
+            Err("Unknown strategy `cheat`, expected one of part1, part2 or one of outcomes:, shapes:, shift: followed by letters".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = Registry::default();
+        registry.register("copy", |elf, _| elf);
+        registry.register_kind("always", |letters| match letters {
+            "rock" => Ok(Arc::new(Shapes([Shape::Rock; 3]))),
+            _ => Err("Only rock".to_string()),
+        });
+        let rounds = Day2::parse(INPUT).unwrap();
+        // Copying the elf is a draw every round
+        let copy = registry.get("copy").unwrap();
+        assert_eq!(score(&rounds, &*copy), 3 * 3 + 1 + 2 + 3);
+        let rock = registry.get("always:rock").unwrap();
+        assert_eq!(score(&rounds, &*rock), 4 + 1 + 7);
+        assert!(registry.get("always:paper").is_err());
+    }
+
+    #[test]
+    fn test_shift() {
+        let rounds = Day2::parse(INPUT).unwrap();
+        // Losing is two steps from the elf's shape, winning one
+        assert_eq!(score(&rounds, &Shift([2, 0, 1])), 12);
+        for elf in Shape::ALL {
+            for (steps, outcome) in [(0, Outcome::Draw), (1, Outcome::Win), (2, Outcome::Loss)] {
+                let shape = Shift([steps; 3]).play(elf, Response::X);
+                assert_eq!(shape.against(elf), outcome);
+            }
+        }
+    }
+}
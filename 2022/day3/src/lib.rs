@@ -0,0 +1,201 @@
+//! 2022 day 3: Rucksack Reorganization.
+
+use aoc_core::{ParseError, Rng, Solution};
+
+/// The two compartments of a rucksack.
+pub fn split_rucksack(content: &str) -> Result<Vec<&str>, String> {
+    if !content.len().is_multiple_of(2) {
+        Err("This can't be divided in two".to_string())
+    } else {
+        let (first, last) = content.split_at(content.len() / 2);
+        Ok(vec![first, last])
+    }
+}
+
+/// Priority of the item found in both compartments, the last one if there are
+/// several. `None` if there is none.
+pub fn find_common_item_priority(rucksack: Vec<&str>) -> Option<u32> {
+    let common = rucksack[0]
+        .chars()
+        .rev()
+        .find(|item| rucksack[1].contains(*item))?;
+    get_item_priority(common)
+}
+
+/// 1 to 26 for `a` to `z` and 27 to 52 for `A` to `Z`, `None` for anything else.
+pub fn get_item_priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// Priority of the badge, the item carried by the 3 elves of a group. `None`
+/// if they have nothing in common.
+pub fn find_common_item_group(rucksacks: Vec<&str>) -> Option<u32> {
+    let badge = rucksacks[0]
+        .chars()
+        .find(|item| rucksacks[1].contains(*item) && rucksacks[2].contains(*item))?;
+    get_item_priority(badge)
+}
+
+/// A rucksack whose compartments only share `common`, with `badge` in one of
+/// them. Every other item comes from `pool`, half of it for each compartment.
+fn generate_rucksack(rng: &mut Rng, common: char, badge: char, pool: &[char]) -> String {
+    let len = rng.range(2..17) as usize;
+    let badge_left = rng.chance(1, 2);
+    let (left_only, right_only) = pool.split_at(pool.len() / 2);
+    let mut compartment = |only: &[char], with_badge: bool| {
+        let mut items = vec![common];
+        if with_badge {
+            items.push(badge);
+        }
+        while items.len() < len {
+            items.push(*rng.choose(only));
+        }
+        rng.shuffle(&mut items);
+        items.into_iter().collect::<String>()
+    };
+    compartment(left_only, badge_left) + &compartment(right_only, !badge_left)
+}
+
+/// Elves go by groups of 3, which `parse` makes sure of.
+pub fn split_into_group(lines: &[String]) -> Vec<Vec<&str>> {
+    lines
+        .chunks(3)
+        .map(|group| group.iter().map(String::as_str).collect())
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = aoc_core::lines(input).collect();
+        for line in &lines {
+            if let Some((idx, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    input,
+                    &line[idx..],
+                    "expected an item (a-z or A-Z)",
+                ));
+            }
+            let compartments = split_rucksack(line).map_err(|e| ParseError::at(input, line, e))?;
+            if find_common_item_priority(compartments).is_none() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "no item is in both compartments",
+                ));
+            }
+        }
+        if !lines.len().is_multiple_of(3) {
+            return Err(ParseError::after(
+                input,
+                input.trim_end(),
+                format!(
+                    "expected groups of 3 rucksacks, the last group has {}",
+                    lines.len() % 3
+                ),
+            ));
+        }
+        for group in lines.chunks(3) {
+            if find_common_item_group(group.to_vec()).is_none() {
+                return Err(ParseError::at(
+                    input,
+                    group[0],
+                    "the 3 rucksacks of this group have no item in common",
+                ));
+            }
+        }
+        Ok(lines.into_iter().map(String::from).collect())
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .iter()
+            .map(|rucksack| split_rucksack(rucksack).expect("Checked by the parser"))
+            .map(|rucksack| find_common_item_priority(rucksack).expect("Checked by the parser"))
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        split_into_group(rucksacks)
+            .into_iter()
+            .map(|group| find_common_item_group(group).expect("Checked by the parser"))
+            .sum()
+    }
+
+    /// `size` groups of 3 rucksacks. Each item type belongs to a single
+    /// rucksack of the group except the badge, so every rucksack has exactly
+    /// one item in both compartments and every group exactly one in common.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut rucksacks = String::new();
+        for _ in 0..size {
+            let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+            rng.shuffle(&mut items);
+            let (badge, pools) = items.split_first().unwrap();
+            for pool in pools.chunks(pools.len() / 3) {
+                let (common, pool) = pool.split_first().unwrap();
+                rucksacks += &generate_rucksack(rng, *common, *badge, pool);
+                rucksacks.push('\n');
+            }
+        }
+        Some(rucksacks)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    #[test]
+    fn test_split_rustsack() {
+        assert_eq!(split_rucksack("abcdef").unwrap(), vec! {"abc","def"});
+    }
+    #[test]
+    fn test_find_common_item_priority() {
+        assert_eq!(find_common_item_priority(vec!["abcde", "fghaj"]), Some(1));
+        assert_eq!(find_common_item_priority(vec!["abAde", "fghaA"]), Some(27));
+        assert_eq!(find_common_item_priority(vec!["ab", "cd"]), None);
+        assert_eq!(get_item_priority('\0'), None);
+    }
+    #[test]
+    fn test_incomplete_group() {
+        assert_eq!(Day3::parse("aa\naa\naa\nbb").unwrap_err().line, 4);
+    }
+    #[test]
+    fn reject_no_common_item() {
+        let error = Day3::parse("aa\ncdcd\nefgh\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "no item is in both compartments");
+        let error = Day3::parse("aa\nbb\naa\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+    #[test]
+    fn test_generate() {
+        use std::collections::HashSet;
+        let items = |s: &str| s.chars().collect::<HashSet<char>>();
+        let input = Day3::generate(&mut Rng::new(3), 20).unwrap();
+        let rucksacks = Day3::parse(&input).unwrap();
+        assert_eq!(rucksacks.len(), 60);
+        for rucksack in &rucksacks {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            assert_eq!(items(left).intersection(&items(right)).count(), 1);
+        }
+        for group in rucksacks.chunks(3) {
+            let badges = items(&group[0])
+                .into_iter()
+                .filter(|c| group[1].contains(*c) && group[2].contains(*c));
+            assert_eq!(badges.count(), 1);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! 2022 day 4: Camp Cleanup.
+
+use aoc_core::parse::{self, token, unsigned, PResult};
+use aoc_core::{ParseError, Rng, Solution};
+
+use nom::{combinator::map, sequence::separated_pair};
+
+/// Sections `start` to `end`, both included.
+pub struct Region {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Region {
+    pub fn parse(s: &str) -> PResult<'_, Self> {
+        map(
+            separated_pair(unsigned, token("-"), unsigned),
+            |(start, end)| Region { start, end },
+        )(s)
+    }
+}
+
+/// A line of the input: the regions of two elves.
+pub fn create_pair(line: &str) -> PResult<'_, (Region, Region)> {
+    separated_pair(Region::parse, token(","), Region::parse)(line)
+}
+
+pub fn do_region_fully_overlap(a: &Region, b: &Region) -> bool {
+    if a.start >= b.start && a.end <= b.end {
+        return true;
+    }
+    if b.start >= a.start && b.end <= a.end {
+        return true;
+    }
+    false
+}
+
+pub fn do_region_overlap(a: &Region, b: &Region) -> bool {
+    // Check if b overlaps a
+    if b.start <= a.start && b.end >= a.start {
+        return true;
+    }
+    if b.start >= a.start && b.end <= a.end {
+        return true;
+    }
+    // Check if a overlaps b
+    if a.start <= b.start && a.end >= b.start {
+        return true;
+    }
+    if a.start >= b.start && a.end <= b.end {
+        return true;
+    }
+    false
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<(Region, Region)>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, create_pair)
+    }
+
+    fn part1(pairs: &Self::Input) -> u32 {
+        pairs
+            .iter()
+            .map(|(a, b)| do_region_fully_overlap(a, b))
+            .map(|o| o as u32)
+            .sum()
+    }
+
+    fn part2(pairs: &Self::Input) -> u32 {
+        pairs
+            .iter()
+            .map(|(a, b)| do_region_overlap(a, b))
+            .map(|o| o as u32)
+            .sum()
+    }
+
+    /// `size` pairs of regions within sections 1 to 99.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut region = || {
+            let start = rng.range(1..100);
+            format!("{}-{}", start, rng.range(start..100))
+        };
+        let pairs: String = (0..size)
+            .map(|_| format!("{},{}\n", region(), region()))
+            .collect();
+        Some(pairs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    /// A line of the input, each region having its start before its end.
+    fn pair() -> impl Strategy<Value = String> {
+        let region = (1..100u32, 0..20u32).prop_map(|(start, len)| (start, start + len));
+        (region.clone(), region).prop_map(|((a, b), (c, d))| format!("{}-{},{}-{}", a, b, c, d))
+    }
+
+    /// Slow but obviously right: compare the sets of sections of each pair.
+    fn oracle(input: &str) -> (u32, u32) {
+        let sections = |region: &str| -> HashSet<u32> {
+            let (start, end) = region.split_once('-').unwrap();
+            (start.parse().unwrap()..=end.parse().unwrap()).collect()
+        };
+        let (mut contained, mut overlapping) = (0, 0);
+        for line in input.lines() {
+            let (a, b) = line.split_once(',').unwrap();
+            let (a, b) = (sections(a), sections(b));
+            contained += (a.is_subset(&b) || b.is_subset(&a)) as u32;
+            overlapping += !a.is_disjoint(&b) as u32;
+        }
+        (contained, overlapping)
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_oracle(lines in prop::collection::vec(pair(), 1..50)) {
+            let input = lines.join("\n");
+            let pairs = Day4::parse(&input).unwrap();
+            prop_assert_eq!((Day4::part1(&pairs), Day4::part2(&pairs)), oracle(&input));
+        }
+    }
+    #[test]
+    fn test_is_fully_overlapping() {
+        assert!(!do_region_fully_overlap(
+            &Region { start: 1, end: 2 },
+            &Region { start: 3, end: 4 }
+        ));
+        assert!(do_region_fully_overlap(
+            &Region { start: 1, end: 6 },
+            &Region { start: 3, end: 4 }
+        ));
+        assert!(!do_region_fully_overlap(
+            &Region { start: 1, end: 3 },
+            &Region { start: 3, end: 4 }
+        ));
+    }
+    #[test]
+    fn test_is_overlapping() {
+        assert!(!do_region_overlap(
+            &Region { start: 1, end: 2 },
+            &Region { start: 3, end: 4 }
+        ));
+        assert!(do_region_overlap(
+            &Region { start: 1, end: 6 },
+            &Region { start: 3, end: 4 }
+        ));
+        assert!(do_region_overlap(
+            &Region { start: 1, end: 3 },
+            &Region { start: 3, end: 4 }
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+//! 2022 day 5: Supply Stacks.
+
+use aoc_core::parse::{self, token, unsigned, PResult};
+use aoc_core::{ParseError, Rng, Solution};
+
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{consumed, map},
+    sequence::{delimited, preceded, tuple},
+};
+
+/// Move `amount` crates from stack `from` to stack `to`, numbered from 1.
+#[derive(Debug)]
+pub struct Operation {
+    pub amount: u32,
+    pub from: u32,
+    pub to: u32,
+}
+
+impl Operation {
+    pub fn parse(s: &str) -> PResult<'_, Self> {
+        map(
+            tuple((
+                preceded(token("move "), unsigned),
+                preceded(token(" from "), unsigned),
+                preceded(token(" to "), unsigned),
+            )),
+            |(amount, from, to)| Operation { amount, from, to },
+        )(s)
+    }
+}
+
+fn parse_crate(cell: &str) -> PResult<'_, char> {
+    delimited(char('['), anychar, char(']'))(cell)
+}
+
+fn create_containers(input: &str, containers: &str) -> Result<Vec<Vec<String>>, ParseError> {
+    // The last line of the drawing numbers the stacks
+    let stacks = aoc_core::lines(containers)
+        .last()
+        .unwrap_or("")
+        .split_whitespace()
+        .count();
+    let mut containers_vec: Vec<Vec<String>> = vec![vec![]; stacks];
+    for line in aoc_core::lines(containers).rev() {
+        // Each stack takes 3 characters, plus a space between stacks
+        for (idx, cell) in parse::columns(line, 4).enumerate() {
+            let cell = cell.trim_end();
+            if !cell.starts_with('[') {
+                continue;
+            }
+            let item = parse::parse_all(cell, parse_crate).map_err(|e| e.within(input, cell))?;
+            containers_vec
+                .get_mut(idx)
+                .ok_or_else(|| ParseError::at(input, cell, "crate outside of any stack"))?
+                .push(item.to_string());
+        }
+    }
+    Ok(containers_vec)
+}
+
+fn create_moves(input: &str, move_list: &str, stacks: usize) -> Result<Vec<Operation>, ParseError> {
+    let operations = parse::lines(move_list, consumed(Operation::parse))
+        .map_err(|e| e.within(input, move_list))?;
+    operations
+        .into_iter()
+        .map(|(line, operation)| {
+            for stack in [operation.from, operation.to] {
+                if stack == 0 || stack as usize > stacks {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!(
+                            "stack {} does not exist, there are {} stacks",
+                            stack, stacks
+                        ),
+                    ));
+                }
+            }
+            Ok(operation)
+        })
+        .collect()
+}
+
+/// Applies the moves and returns the top crates. The crane moves crates one by
+/// one, unless it can `preserve_order`.
+pub fn solve(
+    containers: &mut [Vec<String>],
+    move_list: &[Operation],
+    preserve_order: bool,
+) -> String {
+    for curr_move in move_list {
+        let from_idx = curr_move.from - 1;
+        let to_idx = curr_move.to - 1;
+        let mut move_vector: Vec<String> = vec![];
+        for _ in 0..curr_move.amount as usize {
+            if let Some(content) = containers[from_idx as usize].pop() {
+                move_vector.push(content);
+            }
+        }
+        if preserve_order {
+            move_vector.reverse();
+            containers[to_idx as usize].append(&mut move_vector);
+        } else {
+            containers[to_idx as usize].append(&mut move_vector);
+        }
+    }
+    let mut tops: String = "".to_string();
+    for container in containers {
+        if let Some(c) = container.pop() {
+            tops += &c;
+        }
+    }
+    tops
+}
+
+/// The stacks, bottom first, and the moves to apply to them.
+#[derive(Debug)]
+pub struct Procedure {
+    pub containers: Vec<Vec<String>>,
+    pub moves: Vec<Operation>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Procedure;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut blocks = aoc_core::blocks(input);
+        let (Some(drawing), Some(moves)) = (blocks.next(), blocks.next()) else {
+            return Err(ParseError::after(
+                input,
+                input.trim_end(),
+                "expected a blank line between the drawing and the moves",
+            ));
+        };
+        if let Some(extra) = blocks.next() {
+            return Err(ParseError::at(
+                input,
+                extra,
+                "expected nothing after the moves",
+            ));
+        }
+        let containers = create_containers(input, drawing)?;
+        let moves = create_moves(input, moves, containers.len())?;
+        Ok(Procedure { containers, moves })
+    }
+
+    fn part1(procedure: &Self::Input) -> String {
+        solve(&mut procedure.containers.clone(), &procedure.moves, false)
+    }
+
+    fn part2(procedure: &Self::Input) -> String {
+        solve(&mut procedure.containers.clone(), &procedure.moves, true)
+    }
+
+    /// 9 stacks of up to 8 crates, then `size` moves (at least one, as the
+    /// input needs a moves section) that never take more crates than the
+    /// stack holds.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let letters: Vec<char> = ('A'..='Z').collect();
+        let mut stacks: Vec<Vec<char>> = (0..9)
+            .map(|_| {
+                (0..rng.range(1..9))
+                    .map(|_| *rng.choose(&letters))
+                    .collect()
+            })
+            .collect();
+        let tallest = stacks.iter().map(Vec::len).max().unwrap();
+        let mut drawing = String::new();
+        for level in (0..tallest).rev() {
+            let cells: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(item) => format!("[{}]", item),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            drawing += cells.join(" ").trim_end();
+            drawing.push('\n');
+        }
+        let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+        drawing += numbers.join(" ").trim_end();
+
+        let mut moves = String::new();
+        for _ in 0..size.max(1) {
+            let filled: Vec<usize> = (0..stacks.len())
+                .filter(|s| !stacks[*s].is_empty())
+                .collect();
+            let from = *rng.choose(&filled);
+            let to = (from + 1 + rng.below(stacks.len() - 1)) % stacks.len();
+            let height = stacks[from].len();
+            let amount = rng.range(1..height as i64 + 1) as usize;
+            let moved = stacks[from].split_off(height - amount);
+            stacks[to].extend(moved);
+            moves += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+        }
+        Some(format!("{}\n\n{}", drawing, moves))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const INPUT: &str = include_str!("../inputs/sample.txt");
+
+    #[test]
+    fn test_part1() {
+        aoc_core::assert_snapshot!("sample_part1", Day5::part1(&Day5::parse(INPUT).unwrap()));
+    }
+    #[test]
+    fn test_part2() {
+        aoc_core::assert_snapshot!("sample_part2", Day5::part2(&Day5::parse(INPUT).unwrap()));
+    }
+}
@@ -0,0 +1,141 @@
+//! 2022 day 6: Tuning Trouble.
+
+use aoc_core::{ParseError, Rng, Solution};
+
+/// Length of the start-of-message marker, the longest one searched.
+const MESSAGE_MARKER: usize = 14;
+
+/// Characters read until the last `min_chain` ones are all different.
+pub fn find_min_no_intersect(input: &str, min_chain: usize) -> u32 {
+    let mut min: u32 = 0;
+    let input = input.to_string();
+    for idx in 0..input.len() - (min_chain - 1) {
+        let slice = &input[idx..idx + min_chain];
+        if intersect(slice) == usize::MAX {
+            min = idx as u32 + min_chain as u32;
+            break;
+        }
+    }
+    min
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let datastream = input.trim();
+        match datastream
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            Some((idx, _)) => Err(ParseError::at(
+                input,
+                &datastream[idx..],
+                "expected a lowercase letter",
+            )),
+            None if datastream.len() < MESSAGE_MARKER => Err(ParseError::after(
+                input,
+                datastream,
+                format!("datastream shorter than {}", MESSAGE_MARKER),
+            )),
+            None => Ok(datastream.to_string()),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        find_min_no_intersect(input, 4)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        find_min_no_intersect(input, MESSAGE_MARKER)
+    }
+
+    /// A datastream of `size` random letters, followed by 14 different ones so
+    /// both markers are always found.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let letters: Vec<char> = ('a'..='z').collect();
+        let mut datastream: String = (0..size).map(|_| *rng.choose(&letters)).collect();
+        let mut marker = letters.clone();
+        rng.shuffle(&mut marker);
+        datastream.extend(&marker[..MESSAGE_MARKER]);
+        Some(datastream + "\n")
+    }
+}
+
+/// Index of the first character repeated later in `input`, `usize::MAX` if none is.
+pub fn intersect(input: &str) -> usize {
+    let input = input.to_string();
+    for idx_a in 0..input.len() {
+        for idx_b in idx_a + 1..input.len() {
+            if input[idx_a..idx_a + 1] == input[idx_b..idx_b + 1] {
+                return idx_a;
+            }
+        }
+    }
+    usize::MAX
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day6::part1(&Day6::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap()),
+            7
+        );
+        assert_eq!(
+            Day6::part1(&Day6::parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap()),
+            5
+        );
+        assert_eq!(
+            Day6::part1(&Day6::parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap()),
+            6
+        );
+        assert_eq!(
+            Day6::part1(&Day6::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap()),
+            10
+        );
+        assert_eq!(
+            Day6::part1(&Day6::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()),
+            11
+        );
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day6::part2(&Day6::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap()),
+            19
+        );
+        assert_eq!(
+            Day6::part2(&Day6::parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap()),
+            23
+        );
+        assert_eq!(
+            Day6::part2(&Day6::parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap()),
+            23
+        );
+        assert_eq!(
+            Day6::part2(&Day6::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap()),
+            29
+        );
+        assert_eq!(
+            Day6::part2(&Day6::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()),
+            26
+        );
+    }
+    #[test]
+    fn reject_short() {
+        let error = Day6::parse("abc\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.message, "datastream shorter than 14");
+    }
+}
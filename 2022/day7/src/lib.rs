@@ -0,0 +1,355 @@
+//! 2022 day 7: No Space Left On Device.
+
+use aoc_core::parse::{self, token, unsigned, PResult};
+use aoc_core::{ParseError, Rng, Solution};
+use camino::Utf8PathBuf;
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    combinator::map,
+    sequence::{preceded, separated_pair},
+};
+
+/// A file or directory of the file system, linked to the others by index.
+#[derive(Debug, Default, PartialEq)]
+pub struct Node {
+    pub idx: usize,
+    pub size: u64,
+    pub name: Utf8PathBuf,
+    pub children: Vec<usize>,
+    pub parent: Option<usize>,
+}
+
+fn parse_path(input: &str) -> PResult<'_, Utf8PathBuf> {
+    map(
+        take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        Into::into,
+    )(input)
+}
+
+#[derive(Debug)]
+struct Ls;
+
+fn parse_ls(input: &str) -> PResult<'_, Ls> {
+    map(token("ls"), |_| Ls)(input)
+}
+
+#[derive(Debug)]
+struct Cd(Utf8PathBuf);
+
+fn parse_cd(input: &str) -> PResult<'_, Cd> {
+    map(preceded(token("cd "), parse_path), Cd)(input)
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Ls,
+    Cd(Utf8PathBuf),
+}
+
+impl From<Ls> for Command {
+    fn from(_ls: Ls) -> Self {
+        Command::Ls
+    }
+}
+
+impl From<Cd> for Command {
+    fn from(_cd: Cd) -> Self {
+        Command::Cd(_cd.0)
+    }
+}
+
+fn parse_command(input: &str) -> PResult<'_, Command> {
+    let (input, _) = token("$ ")(input)?;
+    alt((map(parse_ls, Into::into), map(parse_cd, Into::into)))(input)
+}
+
+#[derive(Debug)]
+pub enum Entry {
+    File(u64, Utf8PathBuf),
+    Dir(Utf8PathBuf),
+}
+
+fn parse_entry(input: &str) -> PResult<'_, Entry> {
+    let parse_file = map(
+        separated_pair(unsigned, token(" "), parse_path),
+        |(size, path)| Entry::File(size, path),
+    );
+    let parse_dir = map(preceded(token("dir "), parse_path), Entry::Dir);
+
+    alt((parse_file, parse_dir))(input)
+}
+
+/// A line of the terminal output.
+#[derive(Debug)]
+pub enum Line {
+    Command(Command),
+    Entry(Entry),
+}
+
+pub fn parse_line(input: &str) -> PResult<'_, Line> {
+    alt((
+        map(parse_command, Line::Command),
+        map(parse_entry, Line::Entry),
+    ))(input)
+}
+
+fn get_dir_idx(tree: &[Node], current_parent: usize, name: &str) -> Option<usize> {
+    if let Some(parent) = tree.get(current_parent) {
+        for child_idx in &parent.children {
+            if let Some(child) = tree.get(*child_idx) {
+                if child.name == name {
+                    return Some(*child_idx);
+                }
+            } else {
+                panic!("Trying to access a children that was never created");
+            }
+        }
+    }
+    None
+}
+
+/// Total size of `node` and everything below it.
+pub fn find_node_size(tree: &[Node], node: &Node) -> u64 {
+    let children_size: u64 = node
+        .children
+        .iter()
+        .map(|n| find_node_size(tree, tree.get(*n).expect("")))
+        .sum();
+    node.size + children_size
+}
+
+/// Every directory with its total size, `/` first.
+pub fn get_folder_size(tree: &[Node]) -> Vec<(String, u64)> {
+    let mut size_vec = vec![];
+    for node in tree {
+        // We assume that only directories will have children
+        if !node.children.is_empty() {
+            size_vec.push((node.name.to_string(), find_node_size(tree, node)));
+        }
+    }
+    size_vec
+}
+
+/// Replays the terminal output to rebuild the file system, `/` at index 0.
+pub fn build_tree(input: &str) -> Result<Vec<Node>, ParseError> {
+    let mut tree: Vec<Node> = vec![Node {
+        idx: 0,
+        name: Utf8PathBuf::from_str("/").unwrap(),
+        size: 0, // TODO: this is not ok... need an option maybe????
+        parent: None,
+        children: vec![],
+    }];
+    let mut current_parent = 0;
+
+    for raw_line in aoc_core::lines(input) {
+        let line = parse::parse_all(raw_line, parse_line).map_err(|e| e.within(input, raw_line))?;
+        match line {
+            Line::Command(cmd) => match cmd {
+                Command::Ls => {}
+                Command::Cd(dir) => {
+                    if dir == "/" {
+                        current_parent = 0;
+                    } else if dir == ".." {
+                        current_parent = tree
+                            .get(current_parent)
+                            .expect("Trying to access unexistant parent node")
+                            .parent
+                            .ok_or_else(|| {
+                                ParseError::at(input, raw_line, "cannot go above `/`")
+                            })?;
+                    } else {
+                        current_parent = get_dir_idx(&tree, current_parent, dir.as_str())
+                            .ok_or_else(|| {
+                                ParseError::at(
+                                    input,
+                                    raw_line,
+                                    format!("unknown directory `{}`", dir),
+                                )
+                            })?;
+                    }
+                }
+            },
+            Line::Entry(entry) => {
+                let mut _size: u64 = 0;
+                let mut _name: Utf8PathBuf;
+                match entry {
+                    Entry::File(size, name) => {
+                        _size = size;
+                        _name = name;
+                    }
+                    Entry::Dir(name) => {
+                        _name = name;
+                    }
+                }
+                let idx = tree.len();
+                tree.get_mut(current_parent)
+                    .expect("Trying to access non-existant parent")
+                    .children
+                    .push(idx);
+                tree.push(Node {
+                    idx,
+                    name: _name,
+                    children: vec![],
+                    size: _size,
+                    parent: Some(current_parent),
+                });
+            }
+        }
+    }
+    Ok(tree)
+}
+
+/// A lowercase name of 1 to 8 letters, not in `taken`.
+fn generate_name(rng: &mut Rng, taken: &[String]) -> String {
+    let letters: Vec<char> = ('a'..='z').collect();
+    loop {
+        let name: String = (0..rng.range(1..9))
+            .map(|_| *rng.choose(&letters))
+            .collect();
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+/// Lists `dir` then visits its subdirectories, as a depth first search would.
+fn write_transcript(
+    dirs: &[Vec<usize>],
+    files: &[Vec<(String, u64)>],
+    names: &[String],
+    dir: usize,
+    out: &mut String,
+) {
+    *out += "$ ls\n";
+    for child in &dirs[dir] {
+        *out += &format!("dir {}\n", names[*child]);
+    }
+    for (name, size) in &files[dir] {
+        *out += &format!("{} {}\n", size, name);
+    }
+    for child in &dirs[dir] {
+        *out += &format!("$ cd {}\n", names[*child]);
+        write_transcript(dirs, files, names, *child, out);
+        *out += "$ cd ..\n";
+    }
+}
+
+/// Size of the disk.
+pub const TOTAL_SIZE: u64 = 70000000;
+/// Free space the update needs.
+pub const NEEDED_SIZE: u64 = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<(String, u64)>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let folders = get_folder_size(&build_tree(input)?);
+        let used = folders.first().map_or(0, |root| root.1);
+        let error = |message| Err(ParseError::after(input, input.trim_end(), message));
+        if used > TOTAL_SIZE {
+            return error(format!(
+                "files take {}, more than the {} of the disk",
+                used, TOTAL_SIZE
+            ));
+        }
+        if TOTAL_SIZE - used >= NEEDED_SIZE {
+            return error(format!(
+                "files take {}, the {} needed are already free",
+                used, NEEDED_SIZE
+            ));
+        }
+        Ok(folders)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input.iter().filter(|x| x.1 <= 100000).map(|x| x.1).sum()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let space_to_free = NEEDED_SIZE - (TOTAL_SIZE - input[0].1);
+        input
+            .iter()
+            .filter(|x| x.1 >= space_to_free)
+            .map(|x| x.1)
+            .min()
+            .unwrap()
+    }
+
+    /// The exploration of `size` directories besides `/`. The files add up
+    /// to between 41M and 69M so there is always a directory to delete in
+    /// part 2, but never more than the disk holds.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let count = size + 1;
+        let mut dirs: Vec<Vec<usize>> = vec![vec![]; count];
+        let mut names = vec!["/".to_string()];
+        for dir in 1..count {
+            let parent = rng.below(dir);
+            let taken: Vec<String> = dirs[parent].iter().map(|c| names[*c].clone()).collect();
+            names.push(generate_name(rng, &taken));
+            dirs[parent].push(dir);
+        }
+        // Pick the files first and share the total between them after
+        let mut files: Vec<Vec<(String, u64)>> = vec![vec![]; count];
+        let mut weights = vec![];
+        for (dir, dir_files) in files.iter_mut().enumerate() {
+            let mut taken: Vec<String> = dirs[dir].iter().map(|c| names[*c].clone()).collect();
+            for _ in 0..rng.range((dir == 0) as i64..5) {
+                let mut name = generate_name(rng, &taken);
+                let extension = rng.choose(&["txt", "dat", "log"]);
+                if rng.chance(1, 2) && !taken.contains(&format!("{}.{}", name, extension)) {
+                    name = format!("{}.{}", name, extension);
+                }
+                taken.push(name.clone());
+                let weight = rng.range(1..1000) as u64;
+                weights.push(weight);
+                dir_files.push((name, weight));
+            }
+        }
+        let total = rng.range(41_000_000..69_000_000) as u64;
+        let weights: u64 = weights.iter().sum();
+        for (_, size) in files.iter_mut().flatten() {
+            *size = (*size * total / weights).max(1);
+        }
+
+        let mut transcript = "$ cd /\n".to_string();
+        write_transcript(&dirs, &files, &names, 0, &mut transcript);
+        Some(transcript)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            parse_path(".abcde").unwrap(),
+            ("", Utf8PathBuf::from_str(".abcde").expect(""))
+        );
+    }
+
+    #[test]
+    fn reject_free_disk() {
+        let error = Day7::parse("$ cd /\n$ ls\n100 a\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(
+            error.message,
+            "files take 100, the 30000000 needed are already free"
+        );
+        assert!(Day7::parse("$ cd /\n$ ls\n").is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! 2022 day 8: Treetop Tree House.
+
+use aoc_core::grid::{Offset, Pos, ORTHOGONAL};
+use aoc_core::{Grid, ParseError, Rng, Solution};
+
+/// The height of every tree.
+pub fn get_map(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(
+        input,
+        |c| c.to_digit(10).map(|height| height as u8),
+        "expected a tree height (0-9)",
+    )
+}
+
+/// The trees seen from `pos` towards `direction`, up to the first one at least
+/// as tall (included) or the edge.
+pub fn viewing_distance(map: &Grid<u8>, pos: Pos, direction: Offset) -> u64 {
+    let mut seen = 0;
+    for other in map.ray(pos, direction) {
+        seen += 1;
+        if map[other] >= map[pos] {
+            break;
+        }
+    }
+    seen
+}
+
+/// Number of trees visible from outside the forest.
+pub fn count_visible(map: &Grid<u8>) -> u64 {
+    // Trees on the edge have nothing in the way on at least one side
+    map.iter()
+        .filter(|(pos, height)| {
+            ORTHOGONAL
+                .iter()
+                .any(|direction| map.ray(*pos, *direction).all(|other| map[other] < **height))
+        })
+        .count() as u64
+}
+
+/// Highest product of the viewing distances in all 4 directions.
+pub fn best_scenic_score(map: &Grid<u8>) -> u64 {
+    map.iter()
+        .map(|(pos, _)| {
+            ORTHOGONAL
+                .iter()
+                .map(|direction| viewing_distance(map, pos, *direction))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Grid<u8>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        count_visible(map)
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        best_scenic_score(map)
+    }
+
+    /// A square forest of `size` trees by side.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(1);
+        let forest: String = (0..side)
+            .map(|_| {
+                let row: String = (0..side).map(|_| rng.range(0..10).to_string()).collect();
+                row + "\n"
+            })
+            .collect();
+        Some(forest)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{get_map, Day8};
+    use aoc_core::{Grid, Solution};
+    use proptest::prelude::*;
+    const INPUT: &str = "30373
+25512
+65332
+33549
+35390";
+    #[test]
+    fn test_map() {
+        assert_eq!(
+            get_map(INPUT).unwrap(),
+            Grid::from_rows(vec![
+                vec![3, 0, 3, 7, 3],
+                vec![2, 5, 5, 1, 2],
+                vec![6, 5, 3, 3, 2],
+                vec![3, 3, 5, 4, 9],
+                vec![3, 5, 3, 9, 0]
+            ])
+        );
+    }
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day8::part1(&Day8::parse(INPUT).unwrap()), 21);
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day8::part2(&Day8::parse(INPUT).unwrap()), 8);
+    }
+
+    /// A rectangular forest of 1 to 12 trees by side.
+    fn forest() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+        })
+    }
+
+    /// Slow but obviously right: rescan the whole line and column of every tree.
+    fn oracle(forest: &[Vec<u8>]) -> (u64, u64) {
+        let (height, width) = (forest.len(), forest[0].len());
+        let (mut visible, mut best) = (0, 0);
+        for row in 0..height {
+            for column in 0..width {
+                let tree = forest[row][column];
+                let lines_of_sight: [Vec<u8>; 4] = [
+                    (0..row).rev().map(|r| forest[r][column]).collect(),
+                    (row + 1..height).map(|r| forest[r][column]).collect(),
+                    (0..column).rev().map(|c| forest[row][c]).collect(),
+                    (column + 1..width).map(|c| forest[row][c]).collect(),
+                ];
+                if lines_of_sight
+                    .iter()
+                    .any(|line| line.iter().all(|t| *t < tree))
+                {
+                    visible += 1;
+                }
+                let mut score = 1;
+                for line in &lines_of_sight {
+                    score *= match line.iter().position(|t| *t >= tree) {
+                        Some(idx) => idx as u64 + 1,
+                        None => line.len() as u64,
+                    };
+                }
+                best = best.max(score);
+            }
+        }
+        (visible, best)
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_oracle(forest in forest()) {
+            let input: Vec<String> = forest
+                .iter()
+                .map(|row| row.iter().map(|t| t.to_string()).collect())
+                .collect();
+            let map = Day8::parse(&input.join("\n")).unwrap();
+            prop_assert_eq!((Day8::part1(&map), Day8::part2(&map)), oracle(&forest));
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! 2022 day 9: Rope Bridge.
+
+use aoc_core::parse::{self, token, unsigned, PResult};
+use aoc_core::{Direction, ParseError, Point, Rng, Solution};
+use std::collections::HashSet;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::{preceded, tuple},
+};
+
+fn direction(input: &str) -> PResult<'_, Direction> {
+    alt((
+        value(Direction::Up, tag("U")),
+        value(Direction::Down, tag("D")),
+        value(Direction::Left, tag("L")),
+        value(Direction::Right, tag("R")),
+    ))(input)
+}
+
+/// Move the head `delta` steps towards `dir`.
+#[derive(Clone, Debug)]
+pub struct Move {
+    pub dir: Direction,
+    pub delta: u32,
+}
+
+impl Move {
+    pub fn parse(input: &str) -> PResult<'_, Self> {
+        map(
+            tuple((direction, preceded(token(" "), unsigned))),
+            |(dir, delta)| Self { dir, delta },
+        )(input)
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<Move>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input, Move::parse)
+    }
+
+    fn part1(moves: &Self::Input) -> u64 {
+        simulate(moves, 2)
+    }
+
+    fn part2(moves: &Self::Input) -> u64 {
+        simulate(moves, 10)
+    }
+
+    /// `size` moves of 1 to 20 steps.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let moves: String = (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['U', 'D', 'L', 'R']),
+                    rng.range(1..21)
+                )
+            })
+            .collect();
+        Some(moves)
+    }
+}
+
+/// Pulls a rope of `knots` knots along `moves` and counts the positions
+/// visited by its tail.
+pub fn simulate(moves: &[Move], knots: usize) -> u64 {
+    let mut seen: HashSet<Point<i32>> = HashSet::new();
+    let mut rope: Vec<Point<i32>> = vec![Point::default(); knots];
+    for _move in moves {
+        for _ in 0.._move.delta {
+            // Move head
+            rope[0] += _move.dir.step();
+            // Move rest of rope, each knot stepping towards the one before once they stop touching
+            for idx in 1..knots {
+                let (ahead, knot) = (rope[idx - 1], rope[idx]);
+                if ahead.chebyshev(knot) > 1 {
+                    rope[idx] += (ahead - knot).signum();
+                }
+            }
+            // Update tail
+            seen.insert(rope[knots - 1]);
+        }
+    }
+    seen.len() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    const INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const INPUT2: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day9::part1(&Day9::parse(INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day9::part2(&Day9::parse(INPUT).unwrap()), 1);
+        assert_eq!(Day9::part2(&Day9::parse(INPUT2).unwrap()), 36);
+    }
+
+    fn motion() -> impl Strategy<Value = String> {
+        (prop::sample::select(vec!['U', 'D', 'L', 'R']), 1..10u32)
+            .prop_map(|(direction, steps)| format!("{} {}", direction, steps))
+    }
+
+    /// Slow but obviously right: move the head one step at a time and apply the
+    /// puzzle's rules to each knot, on plain tuples.
+    fn oracle(input: &str, knots: usize) -> u64 {
+        let mut rope = vec![(0i64, 0i64); knots];
+        let mut visited = BTreeSet::from([(0, 0)]);
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            for _ in 0..steps.parse::<u32>().unwrap() {
+                match direction {
+                    "U" => rope[0].1 += 1,
+                    "D" => rope[0].1 -= 1,
+                    "L" => rope[0].0 -= 1,
+                    _ => rope[0].0 += 1,
+                }
+                for idx in 1..knots {
+                    let (dx, dy) = (rope[idx - 1].0 - rope[idx].0, rope[idx - 1].1 - rope[idx].1);
+                    let touching = dx.abs() <= 1 && dy.abs() <= 1;
+                    if !touching {
+                        rope[idx].0 += dx.signum();
+                        rope[idx].1 += dy.signum();
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+        visited.len() as u64
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_oracle(motions in prop::collection::vec(motion(), 1..100)) {
+            let input = motions.join("\n");
+            let moves = Day9::parse(&input).unwrap();
+            prop_assert_eq!(Day9::part1(&moves), oracle(&input, 2));
+            prop_assert_eq!(Day9::part2(&moves), oracle(&input, 10));
+        }
+    }
+}
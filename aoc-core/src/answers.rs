@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::ParseError;
+
+/// Name of the file, in each year's directory, recording the known answers.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// A verified answer for one part of a puzzle, on one of its input variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub answer: String,
+}
+
+/// Parses an answers file: one `<day> <part> <variant> <answer>` per line.
+///
+/// Line breaks in multi-line answers are written `\n` (and backslashes `\\`).
+/// Blank lines and lines starting with `#` are ignored.
+pub fn parse_answers(input: &str) -> Result<Vec<KnownAnswer>, ParseError> {
+    let mut answers = vec![];
+    for line in input.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.splitn(4, ' ').collect();
+        if fields.len() != 4 {
+            return Err(ParseError::at(
+                input,
+                line,
+                "expected `<day> <part> <variant> <answer>`",
+            ));
+        }
+        let number = |field: &str| {
+            field
+                .parse::<u8>()
+                .map_err(|e| ParseError::at(input, field, e.to_string()))
+        };
+        answers.push(KnownAnswer {
+            day: number(fields[0])?,
+            part: number(fields[1])?,
+            variant: fields[2].to_string(),
+            answer: unescape(fields[3]),
+        });
+    }
+    Ok(answers)
+}
+
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+impl fmt::Display for KnownAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.day,
+            self.part,
+            self.variant,
+            self.answer.replace('\\', "\\\\").replace('\n', "\\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answers::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# day part variant answer\n\n7 2 sample 24933642\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![KnownAnswer {
+                day: 7,
+                part: 2,
+                variant: "sample".to_string(),
+                answer: "24933642".to_string(),
+            }]
+        );
+        assert_eq!(parse_answers("7 x real 1").unwrap_err().column, 3);
+        assert_eq!(parse_answers("7 1 real").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_multiline_roundtrip() {
+        let answer = KnownAnswer {
+            day: 10,
+            part: 2,
+            variant: "real".to_string(),
+            answer: "#..#\n.\\n.#".to_string(),
+        };
+        let line = answer.to_string();
+        assert_eq!(line, "10 2 real #..#\\n.\\\\n.#");
+        assert_eq!(parse_answers(&line).unwrap(), vec![answer]);
+    }
+}
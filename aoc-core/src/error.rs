@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+
+/// An input that could not be parsed, located by line and column.
+///
+/// Errors are built from the offending slice of the input, so solutions can
+/// report exactly where parsing stopped instead of panicking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Year and day of the solution that rejected the input, when known.
+    pub day: Option<(u16, u8)>,
+    /// 1-based line of the error.
+    pub line: usize,
+    /// 1-based column (in characters) of the error.
+    pub column: usize,
+    /// Whole line the error is on.
+    pub text: String,
+    pub message: String,
+    offset: usize,
+}
+
+impl ParseError {
+    /// Error located at the start of `at`, which must be a slice of `input`.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        Self::at_offset(input, offset, message.into())
+    }
+
+    /// Error located right after `at`, for input that stopped too early.
+    pub fn after(input: &str, at: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &at[at.len()..], message)
+    }
+
+    fn at_offset(input: &str, offset: usize, message: String) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: input[line_start..].lines().next().unwrap_or("").to_string(),
+            message,
+            offset,
+        }
+    }
+
+    /// Moves an error found while parsing `part` to its location in `input`,
+    /// `part` being a slice of `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let base = Self::at(input, part, "").offset;
+        Self::at_offset(input, (base + self.offset).min(input.len()), self.message)
+    }
+
+    pub fn for_day(self, year: u16, day: u8) -> Self {
+        ParseError {
+            day: Some((year, day)),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((year, day)) = self.day {
+            write!(f, "{} day {}, ", year, day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        let gutter = self.line.to_string().len();
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use crate::error::*;
+
+    #[test]
+    fn test_location() {
+        let input = "1-2\n3-x\n";
+        let error = ParseError::at(input, &input[6..], "invalid digit");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "3-x");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid digit\n2 | 3-x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_after() {
+        let input = "abc\ndef";
+        let error = ParseError::after(input, &input[4..], "truncated");
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "noop\naddx 12z";
+        let line = &input[5..];
+        let error = ParseError::at(line, &line[7..], "expected a number").within(input, line);
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.text, "addx 12z");
+    }
+}
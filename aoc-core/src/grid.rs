@@ -0,0 +1,272 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// Position in a grid, as `(row, column)` from the top left corner.
+pub type Pos = (usize, usize);
+
+/// Step between two positions, as `(rows, columns)`.
+pub type Offset = (isize, isize);
+
+/// Up, right, down and left.
+pub const ORTHOGONAL: [Offset; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The orthogonal directions and the diagonals, clockwise from up.
+pub const ALL_DIRECTIONS: [Offset; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular 2D map, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows must all have the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a map with one character per cell, one row per line, taken as
+    /// they are so spaces can be cells. `cell` returns `None` for characters
+    /// that are not part of the map, `expected` describing those that are.
+    pub fn parse(
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for line in crate::input::lines(input) {
+            let row = line
+                .char_indices()
+                .map(|(idx, c)| {
+                    cell(c).ok_or_else(|| ParseError::at(input, &line[idx..], expected))
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if let Some(first) = rows.first() {
+                if first.len() != row.len() {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!(
+                            "expected {} cells like the first row, got {}",
+                            first.len(),
+                            row.len()
+                        ),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let width = self.width;
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * width + pos.1])
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx / width, idx % width), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width, which only happens for an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "Column {} out of the grid", column);
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// The position one `offset` away from `pos`, if it is still in the grid.
+    pub fn step(&self, (row, column): Pos, (rows, columns): Offset) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(rows)?,
+            column.checked_add_signed(columns)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |offset| self.step(pos, *offset))
+    }
+
+    /// The up to 8 neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |offset| self.step(pos, *offset))
+    }
+
+    /// The positions from `pos` (excluded) towards `direction`, up to the edge.
+    pub fn ray(&self, pos: Pos, direction: Offset) -> impl Iterator<Item = Pos> + '_ {
+        let mut current = Some(pos);
+        std::iter::from_fn(move || {
+            current = self.step(current?, direction);
+            current
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of the {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of the {}x{} grid", pos, width, height))
+    }
+}
+
+/// Prints the cells of each row side by side, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10), "expected a digit")
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected a digit");
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!(error.message, "expected 3 cells like the first row, got 2");
+
+        // Leading spaces are cells, not indentation
+        let error = digits("123\n  456").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let map = Grid::parse("  #\n# #\n", Some, "").unwrap();
+        assert_eq!(
+            map,
+            Grid::from_rows(vec![vec![' ', ' ', '#'], vec!['#', ' ', '#']])
+        );
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = digits("123\n456").unwrap();
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        grid[(0, 1)] = 9;
+        assert_eq!(grid.row(0), &[1, 9, 3]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&9, &5]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(
+            grid.ray((0, 1), (0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (0, 3)]
+        );
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+}
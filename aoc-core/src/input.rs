@@ -0,0 +1,191 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Variant used when no input is specified: the actual puzzle input.
+pub const DEFAULT_VARIANT: &str = "real";
+
+/// Directory holding the named input variants of the crate it is expanded in.
+#[macro_export]
+macro_rules! inputs_dir {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/inputs")
+    };
+}
+
+/// Where a puzzle input is read from.
+///
+/// `-` means stdin, anything that looks like a path (contains a `/` or a `.`)
+/// is a file and any other word is a variant, looked up as `<name>.txt` in the
+/// day's inputs directory (e.g. `sample` or `real`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Variant(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Variant(DEFAULT_VARIANT.to_string())
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(s: &str) -> Self {
+        if s == "-" {
+            InputSource::Stdin
+        } else if s.contains(['/', '.']) {
+            InputSource::File(s.into())
+        } else {
+            InputSource::Variant(s.to_string())
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Variant(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl InputSource {
+    /// Whether this source is the same for every day, as opposed to a variant
+    /// which resolves to a different file per day.
+    pub fn is_shared(&self) -> bool {
+        !matches!(self, InputSource::Variant(_))
+    }
+
+    /// Reads the whole input, resolving variants against `inputs_dir`.
+    pub fn read(&self, inputs_dir: &str) -> Result<String, String> {
+        match self {
+            InputSource::Stdin => io::read_to_string(io::stdin())
+                .map_err(|e| format!("Impossible to read stdin: {}", e)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Variant(name) => {
+                read_file(&Path::new(inputs_dir).join(format!("{}.txt", name)))
+            }
+        }
+    }
+}
+
+/// Byte order mark some editors write at the start of text files.
+pub const BOM: char = '\u{feff}';
+
+/// Rewrites an input so solutions see the same text whatever saved it: no byte
+/// order mark, `\n` line endings, no trailing whitespace on lines and no blank
+/// lines at the end.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let mut normalized = input
+        .lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    normalized.truncate(normalized.trim_end().len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Lines of a normalised input, blank lines before and after it skipped.
+pub fn lines(input: &str) -> impl DoubleEndedIterator<Item = &str> {
+    input.trim_matches('\n').lines()
+}
+
+/// Blocks of a normalised input, separated by one or more blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// Stable fingerprint of an input (64-bit FNV-1a), to tell which input a result comes from.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Names of the variants found in `inputs_dir`, sorted.
+pub fn variants(inputs_dir: &str) -> Vec<String> {
+    let mut variants: Vec<String> = fs::read_dir(inputs_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    variants.sort();
+    variants
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Impossible to read {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::input::*;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(InputSource::from_str("-").unwrap(), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_str("sample").unwrap(),
+            InputSource::Variant("sample".to_string())
+        );
+        assert_eq!(
+            InputSource::from_str("inputs.txt").unwrap(),
+            InputSource::File("inputs.txt".into())
+        );
+        assert_eq!(
+            InputSource::from_str("../day7/custom").unwrap(),
+            InputSource::File("../day7/custom".into())
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("\u{feff}1000\r\n2000  \r\n\r\n3000\r\n\r\n \r\n"),
+            "1000\n2000\n\n3000\n"
+        );
+        assert_eq!(normalize("  [D]  \nmove"), "  [D]\nmove\n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let input = normalize("\r\n1\r\n2\r\n\r\n3\r\n");
+        assert_eq!(lines(&input).collect::<Vec<_>>(), vec!["1", "2", "", "3"]);
+        assert_eq!(blocks(&input).collect::<Vec<_>>(), vec!["1\n2", "3"]);
+        assert_eq!(blocks("1\n\n\n2\n").collect::<Vec<_>>(), vec!["1", "2"]);
+        assert_eq!(blocks("1\n\n\n\n2\n").collect::<Vec<_>>(), vec!["1", "2"]);
+        assert_eq!(blocks("").count(), 0);
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+}
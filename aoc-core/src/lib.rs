@@ -0,0 +1,183 @@
+use std::env;
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+use log::LevelFilter;
+
+mod answers;
+mod error;
+pub mod grid;
+mod input;
+pub mod parse;
+pub mod point;
+mod rng;
+pub mod snapshot;
+
+pub use answers::{parse_answers, KnownAnswer, ANSWERS_FILE};
+pub use error::ParseError;
+pub use grid::Grid;
+pub use input::{
+    blocks, input_hash, lines, normalize, variants, InputSource, BOM, DEFAULT_VARIANT,
+};
+/// Re-exported so the days can log without depending on `log` themselves.
+pub use log;
+pub use point::{Direction, Point, Point3};
+pub use rng::Rng;
+
+/// Environment variable holding the log filter, e.g. `AOC_LOG=day10=trace`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// A puzzle solution for a given day.
+///
+/// The raw input is first normalised (see `normalize`) and turned into `Input`
+/// by `parse`, then both parts are computed from that same parsed input.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Directory of the named input variants, usually `inputs_dir!()`.
+    const INPUTS_DIR: &'static str;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    /// A random valid input, `size` scaling it in a way that depends on the
+    /// puzzle (e.g. a number of lines). `None` for days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Parts every puzzle is made of.
+pub const PARTS: [u8; 2] = [1, 2];
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    /// Time spent computing the answer, parsing excluded.
+    pub elapsed: Duration,
+}
+
+/// Answers of a puzzle for one input, along with the time spent parsing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// A `Solution` with its types erased, so days with different inputs and
+/// answers can be stored and driven side by side.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub inputs_dir: &'static str,
+    solve: fn(&str, &[u8]) -> Result<Solved, ParseError>,
+    generate: fn(&mut Rng, usize) -> Option<String>,
+}
+
+impl Puzzle {
+    pub fn of<S: Solution>() -> Self {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            inputs_dir: S::INPUTS_DIR,
+            solve: solve::<S>,
+            generate: S::generate,
+        }
+    }
+
+    /// Parses `input` once and returns the answer of each requested part, in order.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Solved, ParseError> {
+        (self.solve)(input, parts)
+    }
+
+    /// A random input of the given `size`, the same for a given `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let input = &normalize(input);
+    let parsed = S::parse(input).map_err(|e| e.for_day(S::YEAR, S::DAY))?;
+    let parse_time = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                1 => S::part1(&parsed).to_string(),
+                2 => S::part2(&parsed).to_string(),
+                _ => panic!("Puzzles only have parts 1 and 2, got {}", part),
+            };
+            Answer {
+                part: *part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+/// Reads the input from `source`, solves both parts and prints the answers.
+pub fn run<S: Solution>(source: &InputSource) -> Result<(), String> {
+    let input = source.read(S::INPUTS_DIR)?;
+    let solved = solve::<S>(&input, &PARTS).map_err(|e| e.to_string())?;
+    for answer in solved.answers {
+        print_answer(answer.part, answer.value);
+    }
+    Ok(())
+}
+
+/// Sends the solutions' logs to stderr. Logging is off unless `verbosity` is
+/// raised (1 for debug, 2 and more for trace) or a filter is set in `LOG_ENV`,
+/// which takes precedence and can target single days (`day10=trace`).
+pub fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_env(LOG_ENV)
+        .format_timestamp(None)
+        .init();
+}
+
+/// Entry point of a day's binary: the optional first argument selects the
+/// input (a path, `-` for stdin or a variant name, `real` by default). Logs
+/// are enabled through `LOG_ENV`.
+pub fn main<S: Solution>() {
+    init_logging(0);
+    let source = match env::args().nth(1) {
+        Some(arg) => InputSource::from(arg.as_str()),
+        None => InputSource::default(),
+    };
+    if let Err(e) = run::<S>(&source) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn print_answer(part: u8, answer: String) {
+    if answer.contains('\n') {
+        // Multi-line answers (e.g. pictures) are easier to read starting on their own line
+        println!("Part {}: \n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
@@ -0,0 +1,281 @@
+//! Building blocks for parsing puzzle inputs with nom.
+//!
+//! Parsers work on `&str` and fail with an [`Error`] that carries a readable
+//! message, so the entry points (`parse_all`, `lines`, `blocks`) can turn it
+//! into a [`ParseError`] pointing at the offending part of the input.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, one_of, space0},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::separated_list0,
+    sequence::{pair, preceded},
+    Finish, IResult,
+};
+
+use crate::ParseError;
+
+/// Why a parser failed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// Remaining input when the parser failed.
+    pub input: &'a str,
+    pub message: String,
+    /// Set once a message describes what was expected, so outer parsers keep
+    /// the most precise one.
+    labelled: bool,
+}
+
+impl<'a> Error<'a> {
+    fn new(input: &'a str, message: impl Into<String>, labelled: bool) -> Self {
+        Error {
+            input,
+            message: message.into(),
+            labelled,
+        }
+    }
+
+    fn label(self, message: &str) -> Self {
+        match self.labelled {
+            true => self,
+            false => Error::new(self.input, message, true),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let message = match kind {
+            ErrorKind::Eof => "unexpected trailing input".to_string(),
+            _ => format!("unexpected input ({})", kind.description()),
+        };
+        Error::new(input, message, false)
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Error::new(input, format!("expected `{}`", c), false)
+    }
+
+    /// Keeps the alternative that went the furthest. When none did, what was
+    /// expected is left for an outer `expected` to describe.
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => Error::new(other.input, other.message, false),
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(_input: &'a str, context: &'static str, other: Self) -> Self {
+        other.label(context)
+    }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, e: E) -> Self {
+        Error::new(input, e.to_string(), true)
+    }
+}
+
+/// Result of the parsers of this module.
+pub type PResult<'a, T> = IResult<&'a str, T, Error<'a>>;
+
+/// Describes what `parser` expects when it fails without a more precise message.
+pub fn expected<'a, T>(
+    message: impl Into<String>,
+    mut parser: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    let message = message.into();
+    move |input| parser(input).map_err(|e| e.map(|e| e.label(&message)))
+}
+
+/// The exact text `token`.
+pub fn token<'a>(token: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    expected(format!("expected `{}`", token), tag(token))
+}
+
+/// A number without sign.
+pub fn unsigned<'a, T>(input: &'a str) -> PResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    map_res(expected("expected a number", digit1), str::parse)(input)
+}
+
+/// A number with an optional `+` or `-` sign.
+pub fn signed<'a, T>(input: &'a str) -> PResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    map_res(
+        expected(
+            "expected a number",
+            recognize(pair(opt(one_of("+-")), digit1)),
+        ),
+        str::parse,
+    )(input)
+}
+
+/// Zero or more `item`s separated by `separator`, e.g. `list(unsigned, ", ")`.
+pub fn list<'a, T>(
+    item: impl FnMut(&'a str) -> PResult<'a, T>,
+    separator: &'static str,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    separated_list0(tag(separator), item)
+}
+
+/// A `<key><value>` line such as `Test: divisible by 23`, leading indentation
+/// ignored. Returns the value.
+pub fn key_value<'a, T>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    preceded(pair(space0, token(key)), value)
+}
+
+/// Splits `line` into columns of `width` characters, the last one possibly shorter.
+pub fn columns(line: &str, width: usize) -> impl Iterator<Item = &str> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(idx, _)| idx);
+        let (column, tail) = rest.split_at(end);
+        rest = tail;
+        Some(column)
+    })
+}
+
+/// Runs `parser` on the whole of `input`.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> Result<T, ParseError> {
+    all_consuming(parser)(input)
+        .finish()
+        .map(|(_, parsed)| parsed)
+        .map_err(|e| ParseError::at(input, e.input, e.message))
+}
+
+/// Parses every line of a normalised `input` with `line`.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut line: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    crate::input::lines(input)
+        .map(|l| parse_all(l, &mut line).map_err(|e| e.within(input, l)))
+        .collect()
+}
+
+/// Parses every blank-line separated block of a normalised `input` with `block`.
+pub fn blocks<'a, T>(
+    input: &'a str,
+    mut block: impl FnMut(&'a str) -> PResult<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    crate::input::blocks(input)
+        .map(|b| parse_all(b, &mut block).map_err(|e| e.within(input, b)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use nom::branch::alt;
+    use nom::sequence::separated_pair;
+
+    use crate::parse::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_all("42", unsigned::<u8>), Ok(42));
+        assert_eq!(parse_all("-42", signed::<i32>), Ok(-42));
+        let error = parse_all("x", unsigned::<u8>).unwrap_err();
+        assert_eq!(error.message, "expected a number");
+        let error = parse_all("300", unsigned::<u8>).unwrap_err();
+        assert_eq!(error.message, "number too large to fit in target type");
+        let error = parse_all("12a", unsigned::<u8>).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (3, "unexpected trailing input")
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(
+            parse_all("1, -2, 3", list(signed::<i64>, ", ")),
+            Ok(vec![1, -2, 3])
+        );
+        assert_eq!(parse_all("", list(signed::<i64>, ", ")), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_key_value() {
+        let mut parser = key_value("Test: divisible by ", unsigned::<u64>);
+        assert_eq!(parse_all("  Test: divisible by 23", &mut parser), Ok(23));
+        let error = parse_all("  Test: multiple of 23", &mut parser).unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.message, "expected `Test: divisible by `");
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let pair = |s| separated_pair(unsigned::<u32>, token("-"), unsigned::<u32>)(s);
+        assert_eq!(lines("\n1-2\n3-4\n\n", pair), Ok(vec![(1, 2), (3, 4)]));
+        let error = lines("1-2\n3+4", pair).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected `-`");
+
+        let block = |s| list(unsigned::<u32>, "\n")(s);
+        assert_eq!(blocks("1\n2\n\n3\n", block), Ok(vec![vec![1, 2], vec![3]]));
+        let error = blocks("1\n\n2\n3x", block).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 2));
+    }
+
+    #[test]
+    fn test_expected() {
+        let mut op = expected(
+            "expected `+ <n>` or `* <n>`",
+            alt((
+                preceded(token("+ "), unsigned::<u32>),
+                preceded(token("* "), unsigned::<u32>),
+            )),
+        );
+        // No alternative matched at all
+        assert_eq!(
+            parse_all("/ 2", &mut op).unwrap_err().message,
+            "expected `+ <n>` or `* <n>`"
+        );
+        // One alternative went further and knows better
+        let error = parse_all("* x", &mut op).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (3, "expected a number")
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(
+            columns("[A] [B]     [D]", 4).collect::<Vec<_>>(),
+            vec!["[A] ", "[B] ", "    ", "[D]"]
+        );
+        assert_eq!(columns("", 4).count(), 0);
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed integers that can be used as point coordinates.
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// Point or vector in 2D, with `y` growing upwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Point or vector in 3D.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Component-wise operations shared by `Point` and `Point3`.
+macro_rules! point {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T: Coordinate> $point<T> {
+            pub fn new($($c: T),*) -> Self {
+                $point { $($c),* }
+            }
+
+            /// Every component set to -1, 0 or 1 according to its sign: the
+            /// single step towards a vector's direction.
+            pub fn signum(self) -> Self {
+                $point { $($c: self.$c.signum()),* }
+            }
+
+            /// Distance when moving along one axis at a time.
+            pub fn manhattan(self, other: Self) -> T {
+                let diff = self - other;
+                T::ZERO $(+ diff.$c.abs())*
+            }
+
+            /// Distance when diagonal moves are allowed.
+            pub fn chebyshev(self, other: Self) -> T {
+                let diff = self - other;
+                [$(diff.$c.abs()),*].into_iter().max().unwrap()
+            }
+        }
+
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($c: self.$c + other.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($c: self.$c - other.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($c: self.$c * factor),* }
+            }
+        }
+
+        impl<T: Coordinate> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($c: -self.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point!(Point { x, y });
+point!(Point3 { x, y, z });
+
+/// One of the four orthogonal directions in 2D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The unit vector pointing in this direction.
+    pub fn step<T: Coordinate>(self) -> Point<T> {
+        match self {
+            Direction::Up => Point::new(T::ZERO, T::ONE),
+            Direction::Right => Point::new(T::ONE, T::ZERO),
+            Direction::Down => Point::new(T::ZERO, -T::ONE),
+            Direction::Left => Point::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::point::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, -2);
+        let b = Point::new(4, 2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, 4));
+        assert_eq!(a * 3, Point::new(3, -6));
+        assert_eq!(-a, Point::new(-1, 2));
+        assert_eq!((b - a).signum(), Point::new(1, 1));
+        let mut c = Point3::new(1i64, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 5);
+        assert_eq!(c, Point3::new(2, 3, -1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::new(0, 0, 0).manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::new(0, 0, 0).chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        let moved = Direction::ALL
+            .iter()
+            .fold(Point::new(0i32, 0), |p, d| p + d.step());
+        assert_eq!(moved, Point::new(0, 0));
+        assert_eq!(Direction::Down.step::<i8>(), Point::new(0, -1));
+    }
+}
@@ -0,0 +1,151 @@
+//! Snapshot tests: an answer is compared with a file recorded the first time
+//! the test runs, instead of a value pasted in the test. Useful for pictures
+//! and other answers that are easier to read in a file of their own.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable that, set to `1`, makes failing snapshots record the
+/// new value instead of failing.
+pub const UPDATE_ENV: &str = "AOC_UPDATE_SNAPSHOTS";
+
+/// Compares the `Display` of a value with the snapshot `name` of the calling
+/// crate, stored in its `snapshots/<name>.txt`.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::check(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots"),
+            $name,
+            &$actual.to_string(),
+        )
+    };
+}
+
+/// What `compare` did with a snapshot that is not a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    /// There was no snapshot yet, `actual` became it.
+    Recorded,
+    /// The snapshot was different and replaced by `actual`.
+    Updated,
+}
+
+/// Compares `actual` with the snapshot `name` from `dir`, recording it when it
+/// doesn't exist yet, or when it differs and `update` is set. Fails with a
+/// line by line diff otherwise.
+pub fn compare(dir: &Path, name: &str, actual: &str, update: bool) -> Result<Outcome, String> {
+    let path = dir.join(format!("{}.txt", name));
+    let write = |outcome| {
+        fs::create_dir_all(dir)
+            // The final newline keeps editors and git happy, it is not part of the value
+            .and_then(|_| fs::write(&path, format!("{}\n", actual)))
+            .map(|_| outcome)
+            .map_err(|e| format!("Impossible to write {}: {}", path.display(), e))
+    };
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(_) => return write(Outcome::Recorded),
+    };
+    let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+    if expected == actual {
+        Ok(Outcome::Matched)
+    } else if update {
+        write(Outcome::Updated)
+    } else {
+        Err(format!(
+            "snapshot `{}` ({}) does not match, rerun with {}=1 to accept the new value\n{}",
+            name,
+            path.display(),
+            UPDATE_ENV,
+            diff(expected, actual)
+        ))
+    }
+}
+
+/// `compare` for tests, panicking on mismatches. Use it through `assert_snapshot!`.
+#[track_caller]
+pub fn check(dir: &str, name: &str, actual: &str) {
+    let update = env::var(UPDATE_ENV).is_ok_and(|value| value == "1");
+    match compare(Path::new(dir), name, actual, update) {
+        Ok(Outcome::Matched) => {}
+        Ok(Outcome::Recorded) => eprintln!("recorded new snapshot `{}`", name),
+        Ok(Outcome::Updated) => eprintln!("updated snapshot `{}`", name),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// The lines of `expected` and `actual`, the ones only in `expected` starting
+/// with `-` and the ones only in `actual` with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    // common[i][j]: length of the longest common subsequence of old[i..] and new[j..]
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+    let (mut i, mut j, mut lines) = (0, 0, vec![]);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::process;
+
+    use crate::snapshot::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\nc", "a\nb\nc"), "  a\n  b\n  c");
+        assert_eq!(diff("a\nb\nc", "a\nx\nc\nd"), "  a\n- b\n+ x\n  c\n+ d");
+        assert_eq!(diff("", "a"), "+ a");
+    }
+
+    #[test]
+    fn test_compare() {
+        let dir = env::temp_dir().join(format!("aoc-snapshots-{}", process::id()));
+        assert_eq!(
+            compare(&dir, "picture", "#.\n.#", false),
+            Ok(Outcome::Recorded)
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("picture.txt")).unwrap(),
+            "#.\n.#\n"
+        );
+        assert_eq!(
+            compare(&dir, "picture", "#.\n.#", false),
+            Ok(Outcome::Matched)
+        );
+
+        let error = compare(&dir, "picture", "##\n.#", false).unwrap_err();
+        assert!(error.ends_with("- #.\n+ ##\n  .#"), "{}", error);
+        assert_eq!(
+            compare(&dir, "picture", "##\n.#", true),
+            Ok(Outcome::Updated)
+        );
+        assert_eq!(
+            compare(&dir, "picture", "##\n.#", false),
+            Ok(Outcome::Matched)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_core::{input_hash, InputSource, Puzzle, PARTS};
+
+use crate::output::{self, MeasureRecord};
+
+/// Phases of a solution that are timed separately.
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timings of one phase of a puzzle over all the measured iterations.
+pub struct Measure {
+    pub year: u16,
+    pub day: u8,
+    pub phase: &'static str,
+    pub input_hash: String,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// Median timings of a previous run, by year, day and phase.
+pub type Baseline = HashMap<(u16, u8, String), Duration>;
+
+/// Solves every puzzle `warmup + iterations` times, only measuring the last `iterations` runs.
+pub fn bench(
+    puzzles: &[Puzzle],
+    input: &InputSource,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Vec<Measure>, String> {
+    let mut measures = vec![];
+    for puzzle in puzzles {
+        let content = input.read(puzzle.inputs_dir)?;
+        let hash = input_hash(&content);
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+        for iteration in 0..warmup + iterations {
+            let solved = puzzle.solve(&content, &PARTS).map_err(|e| e.to_string())?;
+            if iteration < warmup {
+                continue;
+            }
+            samples[0].push(solved.parse_time);
+            for answer in solved.answers {
+                samples[answer.part as usize].push(answer.elapsed);
+            }
+        }
+        for (phase, mut times) in PHASES.into_iter().zip(samples) {
+            times.sort();
+            measures.push(Measure {
+                year: puzzle.year,
+                day: puzzle.day,
+                phase,
+                input_hash: hash.clone(),
+                min: times[0],
+                median: times[times.len() / 2],
+                max: times[times.len() - 1],
+            });
+        }
+    }
+    Ok(measures)
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/aoc-bench")
+        .join(format!("{}.txt", name))
+}
+
+/// Saves the medians of `measures` as the baseline `name`, one
+/// `<year> <day> <phase> <nanoseconds>` per line.
+pub fn save_baseline(name: &str, measures: &[Measure]) -> Result<(), String> {
+    let path = baseline_path(name);
+    let content: String = measures
+        .iter()
+        .map(|m| format!("{} {} {} {}\n", m.year, m.day, m.phase, m.median.as_nanos()))
+        .collect();
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(&path, content))
+        .map_err(|e| format!("Impossible to write {}: {}", path.display(), e))
+}
+
+pub fn load_baseline(name: &str) -> Result<Baseline, String> {
+    let path = baseline_path(name);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Impossible to read baseline {}: {}", path.display(), e))?;
+    let invalid = |line: &str| format!("Invalid line in {}: `{}`", path.display(), line);
+    content
+        .lines()
+        .map(|line| {
+            let fields: Vec<&str> = line.split(' ').collect();
+            match fields[..] {
+                [year, day, phase, nanos] => Ok((
+                    (
+                        year.parse().map_err(|_| invalid(line))?,
+                        day.parse().map_err(|_| invalid(line))?,
+                        phase.to_string(),
+                    ),
+                    Duration::from_nanos(nanos.parse().map_err(|_| invalid(line))?),
+                )),
+                _ => Err(invalid(line)),
+            }
+        })
+        .collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Change of the median of `measure` relative to the baseline, in percent.
+fn change(measure: &Measure, baseline: Option<&Baseline>) -> Option<f64> {
+    baseline
+        .and_then(|b| b.get(&(measure.year, measure.day, measure.phase.to_string())))
+        .map(|base| {
+            let ratio = measure.median.as_secs_f64() / base.as_secs_f64().max(f64::MIN_POSITIVE);
+            (ratio - 1.0) * 100.0
+        })
+}
+
+pub fn print(measures: &[Measure], baseline: Option<&Baseline>) {
+    println!(
+        "{:>4} {:>3} {:<6} {:>10} {:>10} {:>10} {:>8}",
+        "Year", "Day", "Phase", "Min", "Median", "Max", "Change"
+    );
+    for m in measures {
+        let change = change(m, baseline)
+            .map(|change| format!("{:+.1}%", change))
+            .unwrap_or_default();
+        println!(
+            "{:>4} {:>3} {:<6} {:>10} {:>10} {:>10} {:>8}",
+            m.year,
+            m.day,
+            m.phase,
+            format_duration(m.min),
+            format_duration(m.median),
+            format_duration(m.max),
+            change
+        );
+    }
+}
+
+pub fn print_json(
+    measures: &[Measure],
+    input: &InputSource,
+    iterations: usize,
+    baseline: Option<&Baseline>,
+) {
+    for m in measures {
+        output::print_json(&MeasureRecord {
+            year: m.year,
+            day: m.day,
+            phase: m.phase,
+            input: input.to_string(),
+            input_hash: m.input_hash.clone(),
+            iterations,
+            min_ns: m.min.as_nanos() as u64,
+            median_ns: m.median.as_nanos() as u64,
+            max_ns: m.max.as_nanos() as u64,
+            change_pct: change(m, baseline),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bench::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+}
@@ -0,0 +1,370 @@
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc_core::{input_hash, InputSource, Puzzle, PARTS};
+use clap::{Args, Parser, Subcommand};
+use output::{AnswerRecord, Format};
+
+mod bench;
+mod output;
+mod pool;
+mod registry;
+mod scaffold;
+mod verify;
+mod watch;
+
+#[derive(Parser)]
+#[command(about = "Runs the advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solutions are doing to stderr (`-vv` for more details)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the available solutions
+    List(Selection),
+    /// Run one, several or all of the solutions
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input to use: a file path, `-` for stdin or the name of a variant
+        /// from the day's inputs directory (e.g. `sample`)
+        #[arg(long, default_value_t)]
+        input: InputSource,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Number of solutions solved at the same time (defaults to the number of CPUs)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+    },
+    /// Check the solutions against the recorded answers of every input variant
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        /// Record the answers that are missing from the answers file
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parsing and both parts of the solutions
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Input to use, as for `run`
+        #[arg(long, default_value_t)]
+        input: InputSource,
+        /// Number of measured runs
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Number of runs done before measuring
+        #[arg(long, default_value_t = 2)]
+        warmup: u32,
+        /// Save the medians as the baseline with this name
+        #[arg(long)]
+        save_baseline: Option<String>,
+        /// Compare the medians with the baseline with this name
+        #[arg(long)]
+        baseline: Option<String>,
+        /// How to print the timings
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Rerun the tests and the solution of one day every time its crate or input changes
+    Watch {
+        #[command(flatten)]
+        selection: Selection,
+        /// Input to solve, as for `run` (stdin excepted)
+        #[arg(long, default_value_t)]
+        input: InputSource,
+        /// How often to look for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Print a random input for one solution, e.g. to run or bench it with `--input`
+    Generate {
+        #[command(flatten)]
+        selection: Selection,
+        /// Seed of the random generator, the same seed giving the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big the input is, its meaning depending on the day (lines,
+        /// elves, monkeys...)
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Create the crate of a new day and register it with the workspace and the runner
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Only keep solutions for this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only keep solutions for these days (e.g. `--day 1,3`)
+    #[arg(long, value_delimiter = ',')]
+    day: Vec<u8>,
+}
+
+impl Selection {
+    fn puzzles(&self) -> Vec<Puzzle> {
+        registry::puzzles()
+            .into_iter()
+            .filter(|p| self.year.is_none_or(|year| p.year == year))
+            .filter(|p| self.day.is_empty() || self.day.contains(&p.day))
+            .collect()
+    }
+}
+
+fn list(puzzles: &[Puzzle]) {
+    println!("{:>4} {:>3}", "Year", "Day");
+    for puzzle in puzzles {
+        println!("{:>4} {:>3}", puzzle.year, puzzle.day);
+    }
+}
+
+/// Makes sure `input` can be used for every selected puzzle.
+fn check_input(puzzles: &[Puzzle], input: &InputSource) -> Result<(), String> {
+    if puzzles.is_empty() {
+        return Err("No solution matches the selection".to_string());
+    }
+    if input.is_shared() && puzzles.len() != 1 {
+        return Err(format!(
+            "--input {} needs exactly one solution to run, {} selected",
+            input,
+            puzzles.len()
+        ));
+    }
+    Ok(())
+}
+
+fn run(
+    puzzles: &[Puzzle],
+    part: Option<u8>,
+    input: InputSource,
+    format: Format,
+    jobs: usize,
+) -> Result<(), String> {
+    check_input(puzzles, &input)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => PARTS.to_vec(),
+    };
+    let inputs = puzzles
+        .iter()
+        .map(|puzzle| Ok((puzzle, input.read(puzzle.inputs_dir)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let start = Instant::now();
+    let outcomes = pool::map(&inputs, jobs, |(puzzle, content)| {
+        puzzle.solve(content, &parts)
+    });
+    let wall_time = start.elapsed();
+
+    let (mut failures, mut solving_time) = (0, Duration::ZERO);
+    if format == Format::Table {
+        println!(
+            "{:>4} {:>3} {:>4} {:>10}  Answer",
+            "Year", "Day", "Part", "Time"
+        );
+    }
+    for ((puzzle, content), outcome) in inputs.iter().zip(outcomes) {
+        let hash = input_hash(content);
+        let outcome = match outcome {
+            Ok(solved) => solved.map_err(|e| e.to_string()),
+            Err(panic) => Err(format!("panicked: {}", panic)),
+        };
+        let solved = match outcome {
+            Ok(solved) => solved,
+            Err(e) => {
+                // Keep going so one bad input or bug doesn't hide the other days' answers
+                match format {
+                    Format::Table => eprintln!("error: {} day {}: {}", puzzle.year, puzzle.day, e),
+                    Format::Json => {
+                        output::print_json(&AnswerRecord::error(puzzle, &input, &hash, e))
+                    }
+                }
+                failures += 1;
+                continue;
+            }
+        };
+        solving_time += solved.parse_time;
+        for answer in solved.answers {
+            solving_time += answer.elapsed;
+            if format == Format::Json {
+                let parse_ns = solved.parse_time.as_nanos() as u64;
+                output::print_json(&AnswerRecord::answer(
+                    puzzle, &input, &hash, parse_ns, &answer,
+                ));
+                continue;
+            }
+            let mut lines = answer.value.lines();
+            println!(
+                "{:>4} {:>3} {:>4} {:>10}  {}",
+                puzzle.year,
+                puzzle.day,
+                answer.part,
+                bench::format_duration(answer.elapsed),
+                lines.next().unwrap_or_default()
+            );
+            // Multi-line answers (e.g. pictures) keep their shape under the answer column
+            for line in lines {
+                println!("{:>26}{}", "", line);
+            }
+        }
+    }
+    // The JSON output stays one answer per line, the summary is only for humans
+    let summary = format!(
+        "{} solved, {} failed in {} ({} of solving on {} thread(s))",
+        puzzles.len() - failures,
+        failures,
+        bench::format_duration(wall_time),
+        bench::format_duration(solving_time),
+        jobs.clamp(1, puzzles.len())
+    );
+    match format {
+        Format::Table => println!("{}", summary),
+        Format::Json => eprintln!("{}", summary),
+    }
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!("{} solution(s) failed", failures)),
+    }
+}
+
+fn verify(puzzles: &[Puzzle], record: bool) -> Result<(), String> {
+    let checks = verify::verify(puzzles)?;
+    verify::print(&checks);
+    let count = |f: fn(&verify::Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    let failed = count(|s| matches!(s, verify::Status::Fail { .. } | verify::Status::Error(_)));
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(|s| *s == verify::Status::Pass),
+        failed,
+        count(|s| *s == verify::Status::Missing)
+    );
+    if record {
+        println!("{} answer(s) recorded", verify::record(&checks)?);
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} check(s) failed", failed)),
+    }
+}
+
+fn bench(
+    puzzles: &[Puzzle],
+    input: InputSource,
+    iterations: u32,
+    warmup: u32,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    format: Format,
+) -> Result<(), String> {
+    check_input(puzzles, &input)?;
+    let baseline = baseline
+        .map(|name| bench::load_baseline(&name))
+        .transpose()?;
+    let measures = bench::bench(puzzles, &input, warmup as usize, iterations as usize)?;
+    match format {
+        Format::Table => bench::print(&measures, baseline.as_ref()),
+        Format::Json => {
+            bench::print_json(&measures, &input, iterations as usize, baseline.as_ref())
+        }
+    }
+    if let Some(name) = save_baseline {
+        bench::save_baseline(&name, &measures)?;
+    }
+    Ok(())
+}
+
+fn generate(puzzles: &[Puzzle], seed: u64, size: usize) -> Result<(), String> {
+    let [puzzle] = puzzles else {
+        return Err(format!(
+            "generate needs exactly one solution, {} selected",
+            puzzles.len()
+        ));
+    };
+    let input = puzzle
+        .generate(seed, size)
+        .ok_or_else(|| format!("{} day {} has no input generator", puzzle.year, puzzle.day))?;
+    print!("{}", input);
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    aoc_core::init_logging(cli.verbose);
+    let result = match cli.command {
+        Command::List(selection) => {
+            list(&selection.puzzles());
+            Ok(())
+        }
+        Command::Run {
+            selection,
+            part,
+            input,
+            format,
+            jobs,
+        } => run(
+            &selection.puzzles(),
+            part,
+            input,
+            format,
+            jobs.map_or_else(pool::default_jobs, |jobs| jobs as usize),
+        ),
+        Command::Verify { selection, record } => verify(&selection.puzzles(), record),
+        Command::Bench {
+            selection,
+            input,
+            iterations,
+            warmup,
+            save_baseline,
+            baseline,
+            format,
+        } => bench(
+            &selection.puzzles(),
+            input,
+            iterations,
+            warmup,
+            save_baseline,
+            baseline,
+            format,
+        ),
+        Command::Watch {
+            selection,
+            input,
+            interval,
+        } => match &selection.puzzles()[..] {
+            [puzzle] => watch::watch(puzzle, &input, Duration::from_millis(interval)),
+            puzzles => Err(format!(
+                "watch needs exactly one solution, {} selected",
+                puzzles.len()
+            )),
+        },
+        Command::Generate {
+            selection,
+            seed,
+            size,
+        } => generate(&selection.puzzles(), seed, size),
+        Command::New { year, day } => {
+            scaffold::new_day(year, day).map(|_| println!("Created {}/day{}", year, day))
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
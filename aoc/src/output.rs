@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use aoc_core::{Answer, InputSource, Puzzle};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// How results are printed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for humans
+    #[default]
+    Table,
+    /// One JSON object per line
+    Json,
+}
+
+/// One answer (or the parse error or panic that prevented it) of a `run`.
+#[derive(Serialize, Deserialize)]
+pub struct AnswerRecord {
+    pub year: u16,
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part: Option<u8>,
+    pub input: String,
+    pub input_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ns: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AnswerRecord {
+    fn new(puzzle: &Puzzle, input: &InputSource, input_hash: &str) -> Self {
+        AnswerRecord {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: None,
+            input: input.to_string(),
+            input_hash: input_hash.to_string(),
+            answer: None,
+            parse_ns: None,
+            duration_ns: None,
+            error: None,
+        }
+    }
+
+    pub fn answer(
+        puzzle: &Puzzle,
+        input: &InputSource,
+        input_hash: &str,
+        parse_ns: u64,
+        answer: &Answer,
+    ) -> Self {
+        AnswerRecord {
+            part: Some(answer.part),
+            answer: Some(answer.value.clone()),
+            parse_ns: Some(parse_ns),
+            duration_ns: Some(answer.elapsed.as_nanos() as u64),
+            ..Self::new(puzzle, input, input_hash)
+        }
+    }
+
+    pub fn error(
+        puzzle: &Puzzle,
+        input: &InputSource,
+        input_hash: &str,
+        error: impl Display,
+    ) -> Self {
+        AnswerRecord {
+            error: Some(error.to_string()),
+            ..Self::new(puzzle, input, input_hash)
+        }
+    }
+}
+
+/// Timings of one phase of a puzzle in a `bench`.
+#[derive(Serialize)]
+pub struct MeasureRecord {
+    pub year: u16,
+    pub day: u8,
+    pub phase: &'static str,
+    pub input: String,
+    pub input_hash: String,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_pct: Option<f64>,
+}
+
+/// Prints `record` as a single line of JSON.
+pub fn print_json(record: &impl Serialize) {
+    println!(
+        "{}",
+        serde_json::to_string(record).expect("Records are always serializable")
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::output::*;
+
+    #[test]
+    fn test_answer_record() {
+        let answer = Answer {
+            part: 2,
+            value: "a\nb".to_string(),
+            elapsed: Duration::from_nanos(42),
+        };
+        let record = AnswerRecord::answer(
+            &Puzzle::of::<day2::Day2>(),
+            &InputSource::from("sample"),
+            "0123456789abcdef",
+            7,
+            &answer,
+        );
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2022,"day":2,"part":2,"input":"sample","input_hash":"0123456789abcdef","answer":"a\nb","parse_ns":7,"duration_ns":42}"#
+        );
+    }
+}
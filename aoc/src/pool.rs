@@ -0,0 +1,100 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+
+thread_local! {
+    /// Whether the current thread is one of `map`'s, whose panics are caught.
+    static IN_POOL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Held while the panic hook is replaced, so concurrent calls to `map` don't
+/// restore each other's hook.
+static HOOK: Mutex<()> = Mutex::new(());
+
+/// The number of threads to use when none is asked for.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Calls `f` on every item on up to `jobs` threads, each thread taking the next
+/// item as soon as it is done with the previous one. Results keep the order of
+/// `items`, and a call that panics gives the panic message instead, without
+/// the panic being printed when it happens.
+pub fn map<T, R>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+{
+    let _hook = HOOK.lock().unwrap_or_else(PoisonError::into_inner);
+    let previous: Arc<dyn Fn(&PanicHookInfo<'_>) + Sync + Send> = panic::take_hook().into();
+    let hook = previous.clone();
+    panic::set_hook(Box::new(move |info| {
+        if !IN_POOL.get() {
+            hook(info)
+        }
+    }));
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                IN_POOL.set(true);
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                        .map_err(|payload| panic_message(&*payload));
+                    results.lock().unwrap()[idx] = Some(result);
+                }
+            });
+        }
+    });
+    panic::set_hook(Box::new(move |info| previous(info)));
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Every item is taken by a thread"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pool::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..100).collect();
+        let squares = map(&items, 4, |x| x * x);
+        assert_eq!(squares, items.iter().map(|x| Ok(x * x)).collect::<Vec<_>>());
+        assert_eq!(map(&[] as &[u64], 4, |x| *x), vec![]);
+    }
+
+    #[test]
+    fn test_panic() {
+        let results = map(&[1, 0, 2], 2, |x| {
+            assert!(*x != 0, "zero is not allowed");
+            *x
+        });
+        assert_eq!(
+            results,
+            vec![Ok(1), Err("zero is not allowed".to_string()), Ok(2)]
+        );
+    }
+}
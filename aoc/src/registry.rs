@@ -0,0 +1,41 @@
+use aoc_core::Puzzle;
+
+/// Every solution the runner knows about, sorted by year then day.
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day1::Day1>(),
+        Puzzle::of::<day2::Day2>(),
+        Puzzle::of::<day3::Day3>(),
+        Puzzle::of::<day4::Day4>(),
+        Puzzle::of::<day5::Day5>(),
+        Puzzle::of::<day6::Day6>(),
+        Puzzle::of::<day7::Day7>(),
+        Puzzle::of::<day8::Day8>(),
+        Puzzle::of::<day9::Day9>(),
+        Puzzle::of::<day10::Day10>(),
+        Puzzle::of::<day11::Day11>(),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::registry::*;
+
+    #[test]
+    fn test_generated_inputs() {
+        for puzzle in puzzles() {
+            for seed in 0..20 {
+                for size in [0, 1, 10, 100] {
+                    let input = puzzle.generate(seed, size).unwrap();
+                    assert_eq!(puzzle.generate(seed, size).unwrap(), input);
+                    if let Err(e) = puzzle.solve(&input, &[1, 2]) {
+                        panic!(
+                            "day {} seed {} size {}: {}\n{}",
+                            puzzle.day, seed, size, e, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
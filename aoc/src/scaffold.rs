@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tpl");
+
+/// Year whose crates are named `day<day>`, as they were before there was more
+/// than one year.
+const FIRST_YEAR: u16 = 2022;
+
+/// Name of a day's crate: `day<day>` for `FIRST_YEAR` and `y<year>-day<day>`
+/// for the others, so every year can have a `day1`.
+fn crate_name(year: u16, day: u8) -> String {
+    match year {
+        FIRST_YEAR => format!("day{}", day),
+        _ => format!("y{}-day{}", year, day),
+    }
+}
+
+/// Name of the library of `crate_name`, as used in paths.
+fn lib_name(crate_name: &str) -> String {
+    crate_name.replace('-', "_")
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    let name = crate_name(year, day);
+    template
+        .replace("{{crate}}", &name)
+        .replace("{{lib}}", &lib_name(&name))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds the `<year>/day*` glob to the workspace members, unless it is already there.
+fn add_member(manifest: &str, year: u16) -> Result<String, String> {
+    let member = format!("\"{}/day*\"", year);
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+    let start = manifest
+        .find("members = [")
+        .ok_or("No workspace members in Cargo.toml")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Unterminated workspace members in Cargo.toml")?;
+    Ok(format!(
+        "{}, {}{}",
+        &manifest[..end],
+        member,
+        &manifest[end..]
+    ))
+}
+
+/// Inserts `line` for the day `date` before the first line of `content` whose
+/// `date_of` is later, or after the last line matching `anchor` if there is none.
+fn insert_sorted(
+    content: &str,
+    anchor: impl Fn(&str) -> bool,
+    date_of: impl Fn(&str) -> Option<(u16, u8)>,
+    date: (u16, u8),
+    line: &str,
+) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let idx = match lines
+        .iter()
+        .position(|l| date_of(l).is_some_and(|d| d > date))
+    {
+        Some(idx) => idx,
+        None => lines.iter().rposition(|l| anchor(l))? + 1,
+    };
+    lines.insert(idx, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// The crate name, year and day of a day dependency of the runner, read from
+/// its `../<year>/day<day>` path.
+fn dependency(line: &str) -> Option<(&str, (u16, u8))> {
+    let (name, path) = line.split_once(" = { path = \"../")?;
+    let (year, day) = path.split_once("/day")?;
+    let day = day.split_once('"')?.0;
+    Some((name, (year.parse().ok()?, day.parse().ok()?)))
+}
+
+/// Adds the day's crate to the runner's dependencies, keeping the days sorted
+/// by year then day.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let line = format!(
+        "{} = {{ path = \"../{}/day{}\" }}",
+        crate_name(year, day),
+        year,
+        day
+    );
+    insert_sorted(
+        manifest,
+        |l| dependency(l).is_some() || l == "[dependencies]",
+        |l| dependency(l).map(|(_, date)| date),
+        (year, day),
+        &line,
+    )
+    .ok_or_else(|| "No [dependencies] in aoc/Cargo.toml".to_string())
+}
+
+/// Year and day of every day the runner depends on, by library name.
+fn day_libs(manifest: &str) -> HashMap<String, (u16, u8)> {
+    manifest
+        .lines()
+        .filter_map(dependency)
+        .map(|(name, date)| (lib_name(name), date))
+        .collect()
+}
+
+/// Adds the day's solution to the runner's registry, keeping it sorted by year
+/// then day. `manifest` is the runner's, used to tell the year of each puzzle.
+fn add_puzzle(registry: &str, manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let line = format!(
+        "        Puzzle::of::<{}::Day{}>(),",
+        lib_name(&crate_name(year, day)),
+        day
+    );
+    let libs = day_libs(manifest);
+    insert_sorted(
+        registry,
+        |l| l.contains("Puzzle::of::<"),
+        |l| {
+            let lib = l.split_once("Puzzle::of::<")?.1.split_once("::")?.0;
+            libs.get(lib).copied()
+        },
+        (year, day),
+        &line,
+    )
+    .ok_or_else(|| "No puzzle in aoc/src/registry.rs".to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Impossible to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Impossible to write {}: {}", path.display(), e))
+}
+
+/// Creates the crate of a new day from the templates, with empty inputs, and
+/// registers it with the workspace and the runner.
+pub fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let dir = root.join(year.to_string()).join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc/Cargo.toml");
+    let registry_path = root.join("aoc/src/registry.rs");
+    let runner = read(&runner_path)?;
+    let name = crate_name(year, day);
+    if runner.lines().any(|l| l.starts_with(&format!("{} ", name))) {
+        return Err(format!("A crate named {} is already registered", name));
+    }
+    // Everything is prepared before writing so a failure leaves the tree untouched
+    let workspace = add_member(&read(&workspace_path)?, year)?;
+    let runner = add_dependency(&runner, year, day)?;
+    let registry = add_puzzle(&read(&registry_path)?, &runner, year, day)?;
+
+    fs::create_dir_all(dir.join("src"))
+        .and_then(|_| fs::create_dir_all(dir.join("inputs")))
+        .map_err(|e| format!("Impossible to create {}: {}", dir.display(), e))?;
+    write(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, year, day))?;
+    write(&dir.join("src/main.rs"), &render(MAIN_TEMPLATE, year, day))?;
+    write(&dir.join("src/lib.rs"), &render(LIB_TEMPLATE, year, day))?;
+    write(&dir.join("inputs/real.txt"), "")?;
+    write(&dir.join("inputs/sample.txt"), "")?;
+    write(&workspace_path, &workspace)?;
+    write(&runner_path, &runner)?;
+    write(&registry_path, &registry)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"2022/day*\"]\n";
+        assert_eq!(add_member(manifest, 2022).unwrap(), manifest);
+        assert_eq!(
+            add_member(manifest, 2023).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"2022/day*\", \"2023/day*\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependency_in_order() {
+        let manifest = "[dependencies]\nday1 = { path = \"../2022/day1\" }\ny2023-day1 = { path = \"../2023/day1\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 2022, 2).unwrap(),
+            "[dependencies]\nday1 = { path = \"../2022/day1\" }\nday2 = { path = \"../2022/day2\" }\ny2023-day1 = { path = \"../2023/day1\" }\n"
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\nclap = \"4\"\n", 2022, 1).unwrap(),
+            "[dependencies]\nday1 = { path = \"../2022/day1\" }\nclap = \"4\"\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest =
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../2022/day1\" }\nserde = \"1\"\n";
+        assert_eq!(
+            add_dependency(manifest, 2023, 2).unwrap(),
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../2022/day1\" }\ny2023-day2 = { path = \"../2023/day2\" }\nserde = \"1\"\n"
+        );
+    }
+
+    #[test]
+    fn test_add_puzzle() {
+        let manifest =
+            "day1 = { path = \"../2022/day1\" }\ny2023-day5 = { path = \"../2023/day5\" }\n";
+        let registry = "    vec![\n        Puzzle::of::<day1::Day1>(),\n        Puzzle::of::<y2023_day5::Day5>(),\n    ]\n";
+        assert_eq!(
+            add_puzzle(registry, manifest, 2023, 1).unwrap(),
+            "    vec![\n        Puzzle::of::<day1::Day1>(),\n        Puzzle::of::<y2023_day1::Day1>(),\n        Puzzle::of::<y2023_day5::Day5>(),\n    ]\n"
+        );
+        assert_eq!(
+            add_puzzle(registry, manifest, 2022, 2).unwrap(),
+            "    vec![\n        Puzzle::of::<day1::Day1>(),\n        Puzzle::of::<day2::Day2>(),\n        Puzzle::of::<y2023_day5::Day5>(),\n    ]\n"
+        );
+        assert_eq!(
+            add_puzzle(registry, manifest, 2023, 7).unwrap(),
+            "    vec![\n        Puzzle::of::<day1::Day1>(),\n        Puzzle::of::<y2023_day5::Day5>(),\n        Puzzle::of::<y2023_day7::Day7>(),\n    ]\n"
+        );
+    }
+}
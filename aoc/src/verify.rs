@@ -0,0 +1,174 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use aoc_core::{parse_answers, variants, InputSource, KnownAnswer, Puzzle, ANSWERS_FILE, PARTS};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer recorded for this part and input yet.
+    Missing,
+    /// The input could not be read or parsed.
+    Error(String),
+}
+
+/// Outcome of running one part of a puzzle on one of its input variants.
+pub struct Check {
+    pub year: u16,
+    pub answer: KnownAnswer,
+    pub status: Status,
+}
+
+/// Location of the recorded answers of `year`.
+fn answers_path(year: u16) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(year.to_string())
+        .join(ANSWERS_FILE)
+}
+
+fn load_answers(year: u16) -> Result<Vec<KnownAnswer>, String> {
+    let path = answers_path(year);
+    match fs::read_to_string(&path) {
+        Ok(content) => parse_answers(&content).map_err(|e| format!("In {}, {}", path.display(), e)),
+        // A year without answers just has everything missing
+        Err(_) => Ok(vec![]),
+    }
+}
+
+/// Runs both parts of every puzzle on each of its input variants and compares
+/// the results with the recorded answers.
+pub fn verify(puzzles: &[Puzzle]) -> Result<Vec<Check>, String> {
+    let mut checks = vec![];
+    let mut answers: HashMap<u16, Vec<KnownAnswer>> = HashMap::new();
+    for puzzle in puzzles {
+        if let Entry::Vacant(entry) = answers.entry(puzzle.year) {
+            entry.insert(load_answers(puzzle.year)?);
+        }
+        let known = &answers[&puzzle.year];
+        for variant in variants(puzzle.inputs_dir) {
+            let solved = InputSource::Variant(variant.clone())
+                .read(puzzle.inputs_dir)
+                .and_then(|input| puzzle.solve(&input, &PARTS).map_err(|e| e.to_string()))
+                .map(|solved| solved.answers);
+            for (idx, part) in PARTS.iter().enumerate() {
+                let expected = known
+                    .iter()
+                    .find(|k| k.day == puzzle.day && k.part == *part && k.variant == variant);
+                let (answer, status) = match (&solved, expected) {
+                    (Err(e), _) => (String::new(), Status::Error(e.clone())),
+                    (Ok(answers), None) => (answers[idx].value.clone(), Status::Missing),
+                    (Ok(answers), Some(k)) if k.answer == answers[idx].value => {
+                        (answers[idx].value.clone(), Status::Pass)
+                    }
+                    (Ok(answers), Some(k)) => (
+                        answers[idx].value.clone(),
+                        Status::Fail {
+                            expected: k.answer.clone(),
+                        },
+                    ),
+                };
+                checks.push(Check {
+                    year: puzzle.year,
+                    answer: KnownAnswer {
+                        day: puzzle.day,
+                        part: *part,
+                        variant: variant.clone(),
+                        answer,
+                    },
+                    status,
+                });
+            }
+        }
+    }
+    Ok(checks)
+}
+
+/// Appends the answers of the `Missing` checks to their year's answers file.
+pub fn record(checks: &[Check]) -> Result<usize, String> {
+    let mut recorded = 0;
+    for check in checks.iter().filter(|c| c.status == Status::Missing) {
+        let path = answers_path(check.year);
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Impossible to open {}: {}", path.display(), e))?;
+        let header = if is_new {
+            "# day part variant answer\n"
+        } else {
+            ""
+        };
+        writeln!(file, "{}{}", header, check.answer)
+            .map_err(|e| format!("Impossible to write {}: {}", path.display(), e))?;
+        recorded += 1;
+    }
+    Ok(recorded)
+}
+
+pub fn print(checks: &[Check]) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<8} Status",
+        "Year", "Day", "Part", "Input"
+    );
+    for check in checks {
+        let status = match &check.status {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+            Status::Error(_) => "ERROR",
+        };
+        println!(
+            "{:>4} {:>3} {:>4}  {:<8} {}",
+            check.year, check.answer.day, check.answer.part, check.answer.variant, status
+        );
+        match &check.status {
+            Status::Fail { expected } => {
+                println!("{}", indent("expected: ", expected));
+                println!("{}", indent("got:      ", &check.answer.answer));
+            }
+            Status::Error(e) => println!("{}", indent("", e)),
+            _ => {}
+        }
+    }
+}
+
+/// Indents every line of `text` under the status column, `label` on the first one.
+fn indent(label: &str, text: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let label = if idx == 0 { label } else { "" };
+            format!("{:>25}{:<10}{}", "", label, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::registry;
+    use crate::verify::*;
+
+    #[test]
+    fn test_recorded_answers() {
+        let checks = verify(&registry::puzzles()).unwrap();
+        for check in checks {
+            assert!(
+                matches!(check.status, Status::Pass | Status::Missing),
+                "{} day {} part {} on {}: {:?}",
+                check.year,
+                check.answer.day,
+                check.answer.part,
+                check.answer.variant,
+                check.status
+            );
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aoc_core::{InputSource, Puzzle};
+
+use crate::output::AnswerRecord;
+
+/// Last modification time of every watched file.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Answers of a run, by part.
+type Answers = BTreeMap<u8, String>;
+
+/// Adds `path` to `snapshot`, along with everything below it for a directory.
+/// Missing files are ignored, they show up in the next snapshot once created.
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_path_buf(), modified);
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        collect(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// The cargo that started the runner, falling back to the one on the `PATH`.
+fn cargo() -> Command {
+    Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+}
+
+/// Runs the tests of the crate in `day_dir`, printing their output as they go.
+fn test(day_dir: &Path) -> bool {
+    cargo()
+        .args(["test", "-q", "--manifest-path"])
+        .arg(day_dir.join("Cargo.toml"))
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Solves the day with a freshly built runner, which is the only way to see the
+/// changes made to its source. `None` when it doesn't build or run.
+fn solve(puzzle: &Puzzle, input: &InputSource) -> Option<Answers> {
+    let output = cargo()
+        .args(["run", "-q", "-p", "aoc", "--", "run", "--format", "json"])
+        .args(["--year", &puzzle.year.to_string()])
+        .args(["--day", &puzzle.day.to_string()])
+        .args(["--input", &input.to_string()])
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    let mut answers = Answers::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let record: AnswerRecord = serde_json::from_str(line).ok()?;
+        if let Some(error) = record.error {
+            eprintln!("error: {}", error);
+        }
+        if let (Some(part), Some(answer)) = (record.part, record.answer) {
+            answers.insert(part, answer);
+        }
+    }
+    output.status.success().then_some(answers)
+}
+
+/// `label: value`, with multi-line values (e.g. pictures) indented on their own lines.
+fn labelled(label: &str, value: &str) -> String {
+    match value.contains('\n') {
+        true => format!("{}:\n    {}", label, value.replace('\n', "\n    ")),
+        false => format!("{}: {}", label, value),
+    }
+}
+
+/// Describes each answer of `current` against the `previous` run.
+fn diff(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.and_then(|p| p.get(part)) {
+            None => labelled(&format!("part {} (new)", part), answer),
+            Some(before) if before == answer => {
+                labelled(&format!("part {} (unchanged)", part), answer)
+            }
+            Some(before) => format!(
+                "{}\n{}",
+                labelled(&format!("part {} (changed)", part), answer),
+                labelled("  was", before)
+            ),
+        })
+        .collect()
+}
+
+/// Reruns the tests and the solution of `puzzle` every time its crate or the
+/// input file changes, until interrupted.
+pub fn watch(puzzle: &Puzzle, input: &InputSource, interval: Duration) -> Result<(), String> {
+    let day_dir = Path::new(puzzle.inputs_dir)
+        .parent()
+        .ok_or("The inputs directory has no parent")?;
+    let mut paths = vec![
+        day_dir.join("Cargo.toml"),
+        day_dir.join("src"),
+        day_dir.join("inputs"),
+    ];
+    match input {
+        InputSource::Stdin => return Err("Stdin can't be watched, use a file".to_string()),
+        InputSource::File(path) => paths.push(path.clone()),
+        InputSource::Variant(_) => {}
+    }
+
+    let (mut last, mut answers) = (None, None);
+    loop {
+        let current = snapshot(&paths);
+        if last.as_ref() != Some(&current) {
+            println!(
+                "== {} day {}: running tests and solving `{}`",
+                puzzle.year, puzzle.day, input
+            );
+            last = Some(current);
+            let tests = match test(day_dir) {
+                true => "tests passed",
+                false => "tests FAILED",
+            };
+            match solve(puzzle, input) {
+                Some(current) => {
+                    for line in diff(answers.as_ref(), &current) {
+                        println!("{}", line);
+                    }
+                    answers = Some(current);
+                }
+                // Keep the last answers to compare with once it is fixed
+                None => println!("the solution failed, see above"),
+            }
+            println!("== {}, waiting for changes", tests);
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::watch::*;
+
+    fn answers(values: &[(u8, &str)]) -> Answers {
+        values.iter().map(|(p, a)| (*p, a.to_string())).collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = answers(&[(1, "42"), (2, "#.\n.#")]);
+        assert_eq!(
+            diff(None, &before),
+            vec!["part 1 (new): 42", "part 2 (new):\n    #.\n    .#"]
+        );
+        let after = answers(&[(1, "42"), (2, "##\n.#")]);
+        assert_eq!(
+            diff(Some(&before), &after),
+            vec![
+                "part 1 (unchanged): 42",
+                "part 2 (changed):\n    ##\n    .#\n  was:\n    #.\n    .#"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("Cargo.toml"), "").unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        let files = snapshot(&[
+            dir.join("Cargo.toml"),
+            dir.join("src"),
+            dir.join("missing.txt"),
+        ]);
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec![&dir.join("Cargo.toml"), &dir.join("src/lib.rs")]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
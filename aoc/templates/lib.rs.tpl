@@ -0,0 +1,40 @@
+use aoc_core::{ParseError, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const INPUTS_DIR: &'static str = aoc_core::inputs_dir!();
+
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> usize {
+        0
+    }
+
+    fn part2(_input: &Self::Input) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    const INPUT: &str = include_str!("../inputs/sample.txt");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day{{day}}::part1(&Day{{day}}::parse(INPUT).unwrap()), 0);
+    }
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day{{day}}::part2(&Day{{day}}::parse(INPUT).unwrap()), 0);
+    }
+}
@@ -0,0 +1,5 @@
+use {{lib}}::Day{{day}};
+
+fn main() {
+    aoc_core::main::<Day{{day}}>();
+}